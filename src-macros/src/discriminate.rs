@@ -3,10 +3,20 @@ use proc_macro2::Span;
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::token::Comma;
-use syn::{parse_macro_input, Fields, Ident, ItemEnum, Variant};
+use syn::{parse_macro_input, AttributeArgs, Error, Fields, Ident, ItemEnum, Meta, NestedMeta, Path, Variant};
 
-pub fn discriminate(input: TokenStream) -> TokenStream {
+/// The traits always derived on the discriminant enum.
+const DEFAULT_DERIVES: [&str; 8] = ["Debug", "Copy", "Clone", "Eq", "Hash", "Ord", "PartialEq", "PartialOrd"];
+
+pub fn discriminate(args: TokenStream, input: TokenStream) -> TokenStream {
+	let args: AttributeArgs = parse_macro_input!(args as AttributeArgs);
 	let input: ItemEnum = parse_macro_input!(input as ItemEnum);
+
+	let extra_derives = match parse_derives(&args) {
+		Ok(derives) => derives,
+		Err(error) => return error.to_compile_error().into(),
+	};
+
 	let visibility = &input.vis;
 
 	let type_ident = &input.ident;
@@ -81,20 +91,41 @@ pub fn discriminate(input: TokenStream) -> TokenStream {
 		}
 	};
 
+	let discriminant_all_impl = {
+		let values = variants.iter().map(|variant| {
+			let value_ident = &variant.ident;
+
+			quote! {
+				#discriminant_ident::#value_ident
+			}
+		}).collect::<Vec<_>>();
+
+		quote! {
+			&[#(#values),*]
+		}
+	};
+
 	let discriminant_enum = quote! {
-		#[derive(Debug, Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+		#[derive(Debug, Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd, #(#extra_derives),*)]
 		#visibility enum #discriminant_ident {
 			#variants
 		}
 
+		impl #discriminant_ident {
+			/// Returns every discriminant, in declaration order.
+			#visibility const fn all() -> &'static [Self] {
+				#discriminant_all_impl
+			}
+		}
+
 		impl ::std::fmt::Display for #discriminant_ident {
 			fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
 				#discriminant_display_impl
 			}
 		}
 
-		impl ::std::convert::From<#type_ident> for #discriminant_ident {
-			fn from(source: #type_ident) -> Self {
+		impl #impl_generics ::std::convert::From<#type_ident #type_generics> for #discriminant_ident #where_clause {
+			fn from(source: #type_ident #type_generics) -> Self {
 				source.discriminant()
 			}
 		}
@@ -110,3 +141,32 @@ pub fn discriminate(input: TokenStream) -> TokenStream {
 
 	assembled.into()
 }
+
+/// Collects the traits listed in `derive(...)`, leaving out the ones that are
+/// derived anyway.
+fn parse_derives(args: &[NestedMeta]) -> syn::Result<Vec<Path>> {
+	let mut derives = vec![];
+
+	for arg in args {
+		let list = match arg {
+			NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("derive") => list,
+			arg => return Err(Error::new_spanned(arg, "expected `derive(...)`")),
+		};
+
+		for nested in &list.nested {
+			let path = match nested {
+				NestedMeta::Meta(Meta::Path(path)) => path,
+				nested => return Err(Error::new_spanned(nested, "expected a trait to derive")),
+			};
+
+			let is_default = path.segments.last()
+				.is_some_and(|segment| DEFAULT_DERIVES.iter().any(|derive| segment.ident == derive));
+
+			if !is_default {
+				derives.push(path.clone());
+			}
+		}
+	}
+
+	Ok(derives)
+}
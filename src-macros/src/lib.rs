@@ -21,7 +21,67 @@ use proc_macro::TokenStream;
 /// assert_eq!(message.discriminant(), MessageTypeDiscriminant::Ad);
 /// assert_ne!(message.discriminant(), MessageTypeDiscriminant::Message);
 /// ```
+/// 
+/// The discriminant enum can be displayed as the variant name, and lists every
+/// variant through `all()`:
+/// ```rust
+/// # use snowcat_macros::discriminate;
+/// # 
+/// # #[discriminate]
+/// # enum MessageType {
+/// #     Action(String),
+/// #     Ad(String),
+/// #     Message(String),
+/// # }
+/// # 
+/// assert_eq!(MessageTypeDiscriminant::Ad.to_string(), "Ad");
+/// 
+/// assert_eq!(MessageTypeDiscriminant::all(), &[
+///     MessageTypeDiscriminant::Action,
+///     MessageTypeDiscriminant::Ad,
+///     MessageTypeDiscriminant::Message,
+/// ]);
+/// ```
+/// 
+/// Generic enums keep their generics and where-clause on the `discriminant()`
+/// method and the `From` conversion, while the discriminant enum itself has
+/// none:
+/// ```rust
+/// use snowcat_macros::discriminate;
+/// 
+/// #[discriminate]
+/// enum Event<T>
+/// where T: Clone,
+/// {
+///     Theme(T),
+///     Resize { width: u32, height: u32 },
+///     Close,
+/// }
+/// 
+/// let event: Event<String> = Event::Theme(String::from("dark"));
+/// 
+/// assert_eq!(event.discriminant(), EventDiscriminant::Theme);
+/// assert_eq!(EventDiscriminant::from(Event::<()>::Close), EventDiscriminant::Close);
+/// ```
+/// 
+/// The discriminant enum always derives `Debug`, `Copy`, `Clone`, `Eq`,
+/// `Hash`, `Ord`, `PartialEq` and `PartialOrd`. More traits can be derived on
+/// it with `derive(...)`:
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use snowcat_macros::discriminate;
+/// 
+/// #[discriminate(derive(Serialize, Deserialize, Hash))]
+/// enum MessageType {
+///     Action(String),
+///     Ad(String),
+///     Message(String),
+/// }
+/// 
+/// fn assert_serde<T: Serialize + for<'de> Deserialize<'de>>() {}
+/// assert_serde::<MessageTypeDiscriminant>();
+/// ```
 #[proc_macro_attribute]
-pub fn discriminate(_: TokenStream, input: TokenStream) -> TokenStream {
-	discriminate::discriminate(input)
+pub fn discriminate(args: TokenStream, input: TokenStream) -> TokenStream {
+	discriminate::discriminate(args, input)
 }
@@ -1,5 +1,19 @@
+pub mod sample_on;
+
+use futures_signals::signal::Signal;
 use std::task::Poll;
 
+pub trait SnowcatSignalExt: Signal + Sized {
+	fn sample_on<Trigger>(self, trigger: Trigger) -> sample_on::SampleOn<Self, Trigger>
+	where Trigger: Signal,
+	      Self::Item: Clone,
+	{
+		sample_on::SampleOn::new(self, trigger)
+	}
+}
+
+impl<T> SnowcatSignalExt for T where T: Signal + Sized {}
+
 pub(crate) fn wrap_poll_result<T>(value: T) -> Poll<Option<T>> {
 	Poll::Ready(Some(value))
 }
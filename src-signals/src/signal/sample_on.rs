@@ -0,0 +1,90 @@
+use crate::signal::wrap_poll_result;
+use futures_signals::signal::Signal;
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A signal emitting the latest value of a source signal every time a
+/// trigger signal changes.
+///
+/// The initial value of the trigger is not considered a change, and a trigger
+/// firing before the source has produced a value is ignored. The signal ends
+/// once the trigger does, as nothing could be sampled afterwards.
+#[must_use = "SampleOn does nothing unless polled"]
+#[pin_project(project = SampleOnProj)]
+#[derive(Debug)]
+pub struct SampleOn<Source, Trigger>
+where Source: Signal,
+      Source::Item: Clone,
+      Trigger: Signal,
+{
+	latest: Option<Source::Item>,
+	has_trigger_value: bool,
+	is_source_done: bool,
+
+	#[pin]
+	signal: Source,
+
+	#[pin]
+	trigger: Trigger,
+}
+
+impl<Source, Trigger> SampleOn<Source, Trigger>
+where Source: Signal,
+      Source::Item: Clone,
+      Trigger: Signal,
+{
+	pub(in crate::signal) fn new(signal: Source, trigger: Trigger) -> Self {
+		SampleOn {
+			signal,
+			trigger,
+
+			latest: None,
+			has_trigger_value: false,
+			is_source_done: false,
+		}
+	}
+}
+
+impl<Source, Trigger> Signal for SampleOn<Source, Trigger>
+where Source: Signal,
+      Source::Item: Clone,
+      Trigger: Signal,
+{
+	type Item = Source::Item;
+
+	fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		let SampleOnProj {
+			latest,
+			has_trigger_value,
+			is_source_done,
+			mut signal,
+			mut trigger,
+		} = self.project();
+
+		// bring the source up to date first, so that a trigger firing in the
+		// same poll samples the newest value
+		while !*is_source_done {
+			match signal.as_mut().poll_change(cx) {
+				Poll::Ready(Some(value)) => *latest = Some(value),
+				Poll::Ready(None) => *is_source_done = true,
+				Poll::Pending => break,
+			}
+		}
+
+		loop {
+			match trigger.as_mut().poll_change(cx) {
+				Poll::Ready(Some(_)) if !*has_trigger_value => *has_trigger_value = true,
+
+				Poll::Ready(Some(_)) => {
+					if let Some(value) = latest {
+						return wrap_poll_result(value.clone());
+					}
+				},
+
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			}
+		}
+	}
+}
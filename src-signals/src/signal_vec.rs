@@ -1,12 +1,69 @@
+pub mod dedup_consecutive;
+pub mod dedup_updates;
+pub mod diff_replace;
+pub mod filter_keyed;
+pub mod filter_map;
+pub mod flatten;
 pub mod group_by_key;
+pub mod index_of;
 pub mod merge;
+pub mod merge_n;
+pub mod scan;
+pub mod sectioned;
+pub mod skip;
+pub mod sorted;
+pub mod take;
+pub mod with_removed;
 
 use futures_signals::signal_vec::{SignalVec, VecDiff};
 use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::hash::Hash;
 use std::task::Poll;
 
 pub trait SnowcatSignalVecExt: SignalVec + Sized {
+	fn dedup_consecutive<EqFn>(self, eq_fn: EqFn) -> dedup_consecutive::DedupConsecutive<EqFn, Self>
+	where EqFn: Fn(&Self::Item, &Self::Item) -> bool,
+	      Self::Item: Clone,
+	{
+		dedup_consecutive::DedupConsecutive::new(self, eq_fn)
+	}
+
+	fn dedup_updates(self) -> dedup_updates::DedupUpdates<Self>
+	where Self::Item: Clone + PartialEq,
+	{
+		dedup_updates::DedupUpdates::new(self)
+	}
+
+	fn diff_replace<Key, KeyFn>(self, key_fn: KeyFn) -> diff_replace::DiffReplace<Key, KeyFn, Self>
+	where Key: Eq + Hash,
+	      KeyFn: Fn(&Self::Item) -> Key,
+	      Self::Item: Clone + PartialEq,
+	{
+		diff_replace::DiffReplace::new(self, key_fn)
+	}
+
+	fn filter_keyed<Key, KeyFn, PredFn>(self, key_fn: KeyFn, pred_fn: PredFn) -> filter_keyed::FilterKeyed<Key, KeyFn, PredFn, Self>
+	where Key: Eq,
+	      KeyFn: Fn(&Self::Item) -> Key,
+	      PredFn: Fn(&Self::Item) -> bool,
+	      Self::Item: Clone + PartialEq,
+	{
+		filter_keyed::FilterKeyed::new(self, key_fn, pred_fn)
+	}
+
+	fn filter_map_cloned<Output, MapFn>(self, map_fn: MapFn) -> filter_map::FilterMapCloned<Self, MapFn>
+	where MapFn: FnMut(&Self::Item) -> Option<Output>,
+	{
+		filter_map::FilterMapCloned::new(self, map_fn)
+	}
+
+	fn flatten(self) -> flatten::Flatten<Self>
+	where Self::Item: SignalVec,
+	{
+		flatten::Flatten::new(self)
+	}
+
 	fn group_by_key<Key, KeyFn>(self, key_fn: KeyFn) -> group_by_key::GroupByKey<Key, KeyFn, Self>
 	where Key: Eq + Debug + Clone,
 	      KeyFn: Fn(&Self::Item) -> Key,
@@ -15,6 +72,12 @@ pub trait SnowcatSignalVecExt: SignalVec + Sized {
 		group_by_key::GroupByKey::new(self, key_fn)
 	}
 
+	fn index_of_signal<PredFn>(self, pred_fn: PredFn) -> index_of::IndexOfSignal<Self, PredFn>
+	where PredFn: Fn(&Self::Item) -> bool,
+	{
+		index_of::IndexOfSignal::new(self, pred_fn)
+	}
+
 	fn merge<Other, OrderFn>(self, other: Other, order_fn: OrderFn) -> merge::Merge2<Self, Other, OrderFn>
 	where Self: SignalVec,
 	      Other: SignalVec,
@@ -24,6 +87,95 @@ pub trait SnowcatSignalVecExt: SignalVec + Sized {
 	{
 		merge::Merge2::new(self, other, order_fn)
 	}
+
+	/// Merges two SignalVecs by comparing a key taken from each of their
+	/// items, such as a timestamp.
+	///
+	/// Items whose keys are equal are placed left first, as with
+	/// [`Tiebreak::LeftFirst`](merge::Tiebreak::LeftFirst).
+	///
+	/// # Example
+	/// ```
+	/// use futures_signals::signal_vec::MutableVec;
+	/// use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct Message { timestamp: u64 }
+	///
+	/// #[derive(Debug, Clone)]
+	/// struct Notification { timestamp: u64 }
+	///
+	/// let messages = MutableVec::new_with_values(vec![Message { timestamp: 1 }, Message { timestamp: 3 }]);
+	/// let notifs = MutableVec::new_with_values(vec![Notification { timestamp: 2 }]);
+	///
+	/// let merged = messages.signal_vec_cloned()
+	///     .merge_by_key(notifs.signal_vec_cloned(), |m| m.timestamp, |n| n.timestamp);
+	/// ```
+	fn merge_by_key<Other, Key, LeftKeyFn, RightKeyFn>(self, other: Other, left_key: LeftKeyFn, right_key: RightKeyFn)
+	-> merge::Merge2<Self, Other, impl Fn(&Self::Item, &Other::Item) -> Ordering>
+	where Other: SignalVec,
+	      Key: Ord,
+	      LeftKeyFn: Fn(&Self::Item) -> Key,
+	      RightKeyFn: Fn(&Other::Item) -> Key,
+	      Self::Item: Debug + Clone,
+	      Other::Item: Debug + Clone,
+	{
+		merge::Merge2::new(self, other, move |left: &Self::Item, right: &Other::Item| {
+			left_key(left).cmp(&right_key(right))
+		})
+	}
+
+	fn scan<State, ScanFn>(self, initial: State, scan_fn: ScanFn) -> scan::Scan<Self, State, ScanFn>
+	where Self::Item: Clone,
+	      State: Clone,
+	      ScanFn: FnMut(&mut State, &VecDiff<Self::Item>, &[Self::Item]),
+	{
+		scan::Scan::new(self, initial, scan_fn)
+	}
+
+	fn sectioned<Section, SectionFn>(self, section_fn: SectionFn) -> sectioned::Sectioned<Section, SectionFn, Self>
+	where Section: Ord + Clone,
+	      SectionFn: Fn(&Self::Item) -> Section,
+	      Self::Item: Clone,
+	{
+		sectioned::Sectioned::new(self, section_fn)
+	}
+
+	fn skip(self, count: usize) -> skip::Skip<Self>
+	where Self::Item: Clone,
+	{
+		skip::Skip::new(self, count)
+	}
+
+	fn sorted_by<OrderFn>(self, order_fn: OrderFn) -> sorted::SortedVecSignal<Self, OrderFn>
+	where Self::Item: Clone,
+	      OrderFn: Fn(&Self::Item, &Self::Item) -> Ordering,
+	{
+		sorted::SortedVecSignal::new(self, order_fn)
+	}
+
+	fn sorted_by_key<Key, KeyFn>(self, key_fn: KeyFn)
+	-> sorted::SortedVecSignal<Self, impl Fn(&Self::Item, &Self::Item) -> Ordering>
+	where Key: Ord,
+	      KeyFn: Fn(&Self::Item) -> Key,
+	      Self::Item: Clone,
+	{
+		sorted::SortedVecSignal::new(self, move |left: &Self::Item, right: &Self::Item| {
+			key_fn(left).cmp(&key_fn(right))
+		})
+	}
+
+	fn take(self, count: usize) -> take::Take<Self>
+	where Self::Item: Clone,
+	{
+		take::Take::new(self, count)
+	}
+
+	fn with_removed(self) -> with_removed::WithRemoved<Self>
+	where Self::Item: Clone,
+	{
+		with_removed::WithRemoved::new(self)
+	}
 }
 
 impl<T> SnowcatSignalVecExt for T where T: SignalVec + Sized {}
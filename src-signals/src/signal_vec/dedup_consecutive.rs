@@ -0,0 +1,276 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Collapses runs of consecutive items of a [`SignalVec`] that `eq_fn`
+/// considers equal, such as duplicate broadcasts in a merged chat log.
+///
+/// Only one item of each run is emitted. An item inserted next to an equal
+/// item is dropped, and removing the emitted item of a run emits the next
+/// item of that run in its place. Indices in the emitted diffs are translated
+/// to positions in the collapsed output.
+///
+/// Moves are emitted as a removal followed by an insertion.
+#[must_use = "DedupConsecutive does nothing unless polled"]
+#[pin_project(project = DedupConsecutiveProj)]
+#[derive(Debug)]
+pub struct DedupConsecutive<EqFn, Source>
+where EqFn: Fn(&Source::Item, &Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	entries: Vec<DedupEntry<Source::Item>>,
+	eq_fn: EqFn,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<EqFn, Source> DedupConsecutive<EqFn, Source>
+where EqFn: Fn(&Source::Item, &Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, eq_fn: EqFn) -> Self {
+		DedupConsecutive {
+			signal,
+			eq_fn,
+
+			entries: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<EqFn, Source> SignalVec for DedupConsecutive<EqFn, Source>
+where EqFn: Fn(&Source::Item, &Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let DedupConsecutiveProj {
+			entries,
+			eq_fn,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			match op {
+				VecDiff::Replace { values } => {
+					*entries = values.into_iter()
+						.map(|value| DedupEntry { value, is_visible: false })
+						.collect();
+
+					for index in 0..entries.len() {
+						let is_visible = index == 0 || !eq_fn(&entries[index - 1].value, &entries[index].value);
+						entries[index].is_visible = is_visible;
+					}
+
+					let values = entries.iter()
+						.filter(|entry| entry.is_visible)
+						.map(|entry| entry.value.clone())
+						.collect();
+
+					pending_returns.push_back(VecDiff::Replace { values });
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					insert_at(entries, index, value, eq_fn, pending_returns);
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					if entries[index].is_visible {
+						let translated = translate_index(entries, index);
+						pending_returns.push_back(VecDiff::UpdateAt { index: translated, value: value.clone() });
+					}
+
+					entries[index].value = value;
+					fix_runs(entries, index.saturating_sub(1), index + 2, eq_fn, pending_returns);
+				},
+
+				VecDiff::RemoveAt { index } => {
+					remove_at(entries, index, eq_fn, pending_returns);
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let value = entries[old_index].value.clone();
+
+					remove_at(entries, old_index, eq_fn, pending_returns);
+					insert_at(entries, new_index, value, eq_fn, pending_returns);
+				},
+
+				VecDiff::Push { value } => {
+					let visible = entries.iter().filter(|entry| entry.is_visible).count();
+					insert_at(entries, entries.len(), value, eq_fn, pending_returns);
+
+					// a pushed item that is shown always lands at the end of the
+					// output
+					if let Some(VecDiff::InsertAt { index, .. }) = pending_returns.back() {
+						if *index == visible {
+							if let Some(VecDiff::InsertAt { value, .. }) = pending_returns.pop_back() {
+								pending_returns.push_back(VecDiff::Push { value });
+							}
+						}
+					}
+				},
+
+				VecDiff::Pop {} => {
+					let index = entries.len() - 1;
+					let is_last = entries[index].is_visible;
+
+					remove_at(entries, index, eq_fn, pending_returns);
+
+					if is_last {
+						if let Some(VecDiff::RemoveAt { .. }) = pending_returns.back() {
+							pending_returns.pop_back();
+							pending_returns.push_back(VecDiff::Pop {});
+						}
+					}
+				},
+
+				VecDiff::Clear {} => {
+					if entries.iter().any(|entry| entry.is_visible) {
+						pending_returns.push_back(VecDiff::Clear {});
+					}
+
+					entries.clear();
+				},
+			}
+		}
+	}
+}
+
+#[derive(Debug)]
+struct DedupEntry<Item> {
+	value: Item,
+	is_visible: bool,
+}
+
+fn insert_at<Item, EqFn>(
+	entries: &mut Vec<DedupEntry<Item>>,
+	index: usize,
+	value: Item,
+	eq_fn: &EqFn,
+	pending_returns: &mut VecDeque<VecDiff<Item>>,
+)
+where Item: Clone,
+      EqFn: Fn(&Item, &Item) -> bool,
+{
+	// the new item starts out hidden, and is only emitted if it does not join
+	// the run of one of its neighbours
+	entries.insert(index, DedupEntry { value, is_visible: false });
+	fix_runs(entries, index.saturating_sub(1), index + 2, eq_fn, pending_returns);
+}
+
+fn remove_at<Item, EqFn>(
+	entries: &mut Vec<DedupEntry<Item>>,
+	index: usize,
+	eq_fn: &EqFn,
+	pending_returns: &mut VecDeque<VecDiff<Item>>,
+)
+where Item: Clone,
+      EqFn: Fn(&Item, &Item) -> bool,
+{
+	if entries[index].is_visible {
+		let translated = translate_index(entries, index);
+		pending_returns.push_back(VecDiff::RemoveAt { index: translated });
+	}
+
+	entries.remove(index);
+	fix_runs(entries, index.saturating_sub(1), index + 1, eq_fn, pending_returns);
+}
+
+/// Makes sure that every run of equal items touching `entries[start..end]`
+/// has exactly one visible item.
+///
+/// A run without a visible item shows its first item, and a run with more than
+/// one, after two runs have been joined, only keeps the first of them.
+fn fix_runs<Item, EqFn>(
+	entries: &mut [DedupEntry<Item>],
+	start: usize,
+	end: usize,
+	eq_fn: &EqFn,
+	pending_returns: &mut VecDeque<VecDiff<Item>>,
+)
+where Item: Clone,
+      EqFn: Fn(&Item, &Item) -> bool,
+{
+	let end = end.min(entries.len());
+
+	if start >= end {
+		return;
+	}
+
+	// find the start of the run the window starts in
+	let mut run_start = start;
+
+	while run_start > 0 && eq_fn(&entries[run_start - 1].value, &entries[run_start].value) {
+		run_start -= 1;
+	}
+
+	while run_start < end {
+		let mut run_end = run_start + 1;
+
+		while run_end < entries.len() && eq_fn(&entries[run_end - 1].value, &entries[run_end].value) {
+			run_end += 1;
+		}
+
+		let mut has_visible = false;
+
+		for index in run_start..run_end {
+			if !entries[index].is_visible {
+				continue;
+			}
+
+			if has_visible {
+				let translated = translate_index(entries, index);
+				pending_returns.push_back(VecDiff::RemoveAt { index: translated });
+
+				entries[index].is_visible = false;
+			}
+
+			has_visible = true;
+		}
+
+		if !has_visible {
+			let index = translate_index(entries, run_start);
+			let value = entries[run_start].value.clone();
+
+			entries[run_start].is_visible = true;
+
+			// removing an item and showing another one in its place is an
+			// update
+			match pending_returns.back() {
+				Some(&VecDiff::RemoveAt { index: removed }) if removed == index => {
+					pending_returns.pop_back();
+					pending_returns.push_back(VecDiff::UpdateAt { index, value });
+				},
+
+				_ => pending_returns.push_back(VecDiff::InsertAt { index, value }),
+			}
+		}
+
+		run_start = run_end;
+	}
+}
+
+fn translate_index<Item>(entries: &[DedupEntry<Item>], index: usize) -> usize {
+	entries[..index].iter().filter(|entry| entry.is_visible).count()
+}
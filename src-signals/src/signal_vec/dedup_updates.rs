@@ -0,0 +1,93 @@
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Drops every `UpdateAt` emitted by a [`SignalVec`] whose value is equal to
+/// the item already stored at that index.
+///
+/// A mirror of the source is kept to compare updates against; every other
+/// diff is passed through unchanged.
+#[must_use = "DedupUpdates does nothing unless polled"]
+#[pin_project(project = DedupUpdatesProj)]
+#[derive(Debug)]
+pub struct DedupUpdates<Source>
+where Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	items: Vec<Source::Item>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source> DedupUpdates<Source>
+where Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	pub(in crate::signal_vec) fn new(signal: Source) -> Self {
+		DedupUpdates {
+			signal,
+			items: vec![],
+		}
+	}
+}
+
+impl<Source> SignalVec for DedupUpdates<Source>
+where Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let DedupUpdatesProj {
+			items,
+			mut signal,
+		} = self.project();
+
+		loop {
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			match &op {
+				VecDiff::Replace { values } => *items = values.clone(),
+				VecDiff::InsertAt { index, value } => items.insert(*index, value.clone()),
+
+				VecDiff::UpdateAt { index, value } => {
+					if items[*index] == *value {
+						log::trace!(
+							"{file}:{line} [{module}::<DedupUpdates as SignalVec>::poll_vec_change] dropping redundant update at index {index}",
+							file = file!(), line = line!(), module = module_path!(),
+						);
+
+						continue;
+					}
+
+					items[*index] = value.clone();
+				},
+
+				VecDiff::RemoveAt { index } => {
+					items.remove(*index);
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let value = items.remove(*old_index);
+					items.insert(*new_index, value);
+				},
+
+				VecDiff::Push { value } => items.push(value.clone()),
+
+				VecDiff::Pop {} => {
+					items.pop();
+				},
+
+				VecDiff::Clear {} => items.clear(),
+			}
+
+			return Poll::Ready(Some(op));
+		}
+	}
+}
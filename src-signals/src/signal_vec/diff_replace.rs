@@ -0,0 +1,221 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Breaks every `Replace` emitted by a [`SignalVec`] down into the diffs
+/// needed to turn the previous contents into the new ones, matching items by
+/// key.
+///
+/// Items whose key disappeared are removed, new keys are inserted, and items
+/// whose value changed are updated. Items are only moved when they fall
+/// outside the longest run of items that kept their relative order, so a
+/// `Replace` that only moves one item around is emitted as a single `Move`.
+///
+/// A `Replace` is passed through unchanged if either the previous or the new
+/// contents are empty, or if either of them contains duplicate keys.
+#[must_use = "DiffReplace does nothing unless polled"]
+#[pin_project(project = DiffReplaceProj)]
+#[derive(Debug)]
+pub struct DiffReplace<Key, KeyFn, Source>
+where Key: Eq + Hash,
+      KeyFn: Fn(&Source::Item) -> Key,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	items: Vec<Source::Item>,
+	key_fn: KeyFn,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Key, KeyFn, Source> DiffReplace<Key, KeyFn, Source>
+where Key: Eq + Hash,
+      KeyFn: Fn(&Source::Item) -> Key,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, key_fn: KeyFn) -> Self {
+		DiffReplace {
+			signal,
+			key_fn,
+
+			items: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Key, KeyFn, Source> SignalVec for DiffReplace<Key, KeyFn, Source>
+where Key: Eq + Hash,
+      KeyFn: Fn(&Source::Item) -> Key,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let DiffReplaceProj {
+			items,
+			key_fn,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			if let VecDiff::Replace { values } = op {
+				match diff_items(items, &values, key_fn) {
+					Some(diffs) => pending_returns.extend(diffs),
+					None => pending_returns.push_back(VecDiff::Replace { values: values.clone() }),
+				}
+
+				*items = values;
+				continue;
+			}
+
+			op.clone().apply_to_vec(items);
+			return wrap_poll_result(op);
+		}
+	}
+}
+
+/// Computes the diffs turning `old` into `new`, or `None` if that is not
+/// possible by key.
+fn diff_items<Key, KeyFn, Item>(old: &[Item], new: &[Item], key_fn: &KeyFn) -> Option<Vec<VecDiff<Item>>>
+where Key: Eq + Hash,
+      KeyFn: Fn(&Item) -> Key,
+      Item: Clone + PartialEq,
+{
+	if old.is_empty() || new.is_empty() {
+		return None;
+	}
+
+	let new_positions: HashMap<Key, usize> = new.iter()
+		.enumerate()
+		.map(|(index, value)| (key_fn(value), index))
+		.collect();
+
+	let old_keys: HashSet<Key> = old.iter().map(key_fn).collect();
+
+	if new_positions.len() != new.len() || old_keys.len() != old.len() {
+		return None;
+	}
+
+	let mut diffs = vec![];
+
+	// remove every item whose key is gone, back to front so that the indices
+	// of the items still to be checked do not change. `current` holds the
+	// position each remaining item has in `new`.
+	let mut current: Vec<usize> = Vec::with_capacity(old.len());
+	let mut old_values: HashMap<usize, &Item> = HashMap::with_capacity(old.len());
+
+	for (index, value) in old.iter().enumerate().rev() {
+		match new_positions.get(&key_fn(value)) {
+			Some(&position) => {
+				current.push(position);
+				old_values.insert(position, value);
+			},
+
+			None => diffs.push(VecDiff::RemoveAt { index }),
+		}
+	}
+
+	current.reverse();
+
+	// items in the longest increasing run of positions are already in the
+	// right order relative to each other, and never need to move
+	let stable: HashSet<usize> = longest_increasing_subsequence(&current).into_iter()
+		.map(|index| current[index])
+		.collect();
+
+	// place every item of `new` in order, right after the previous one
+	let mut previous: Option<usize> = None;
+
+	for (position, value) in new.iter().enumerate() {
+		let target = previous.map_or(0, |index| index + 1);
+
+		let index = match current.iter().position(|&other| other == position) {
+			None => {
+				current.insert(target, position);
+				diffs.push(VecDiff::InsertAt { index: target, value: value.clone() });
+
+				previous = Some(target);
+				continue;
+			},
+
+			Some(index) if stable.contains(&position) => index,
+
+			Some(index) => {
+				// the target is relative to the collection after the item is
+				// removed from it
+				let target = if index < target { target - 1 } else { target };
+
+				if index != target {
+					let moved = current.remove(index);
+					current.insert(target, moved);
+
+					diffs.push(VecDiff::Move { old_index: index, new_index: target });
+				}
+
+				target
+			},
+		};
+
+		if old_values.get(&position) != Some(&value) {
+			diffs.push(VecDiff::UpdateAt { index, value: value.clone() });
+		}
+
+		previous = Some(index);
+	}
+
+	Some(diffs)
+}
+
+/// Returns the indices of one of the longest strictly increasing
+/// subsequences of `sequence`.
+fn longest_increasing_subsequence(sequence: &[usize]) -> Vec<usize> {
+	// tails[length] is the index of the smallest value ending a subsequence
+	// of length + 1
+	let mut tails: Vec<usize> = vec![];
+	let mut predecessors: Vec<Option<usize>> = vec![None; sequence.len()];
+
+	for (index, value) in sequence.iter().enumerate() {
+		let length = tails.partition_point(|&tail| sequence[tail] < *value);
+
+		if length > 0 {
+			predecessors[index] = Some(tails[length - 1]);
+		}
+
+		if length == tails.len() {
+			tails.push(index);
+		} else {
+			tails[length] = index;
+		}
+	}
+
+	let mut subsequence = vec![];
+	let mut next = tails.last().copied();
+
+	while let Some(index) = next {
+		subsequence.push(index);
+		next = predecessors[index];
+	}
+
+	subsequence.reverse();
+	subsequence
+}
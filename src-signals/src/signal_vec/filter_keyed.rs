@@ -0,0 +1,232 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Filters a [`SignalVec`] while keeping track of the key and visibility of
+/// every item, so that visibility changes are emitted as single, targeted
+/// diffs.
+///
+/// An `UpdateAt` that flips an item's visibility is emitted as a single
+/// `InsertAt` or `RemoveAt` at the translated index, and a `Replace` whose
+/// keys match the current items one-for-one is broken down into diffs for
+/// only those items that changed, instead of replacing the whole output.
+#[must_use = "FilterKeyed does nothing unless polled"]
+#[pin_project(project = FilterKeyedProj)]
+#[derive(Debug)]
+pub struct FilterKeyed<Key, KeyFn, PredFn, Source>
+where Key: Eq,
+      KeyFn: Fn(&Source::Item) -> Key,
+      PredFn: Fn(&Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	entries: Vec<FilterKeyedEntry<Key, Source::Item>>,
+	key_fn: KeyFn,
+	pred_fn: PredFn,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Key, KeyFn, PredFn, Source> FilterKeyed<Key, KeyFn, PredFn, Source>
+where Key: Eq,
+      KeyFn: Fn(&Source::Item) -> Key,
+      PredFn: Fn(&Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, key_fn: KeyFn, pred_fn: PredFn) -> Self {
+		FilterKeyed {
+			signal,
+			key_fn,
+			pred_fn,
+
+			entries: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Key, KeyFn, PredFn, Source> SignalVec for FilterKeyed<Key, KeyFn, PredFn, Source>
+where Key: Eq,
+      KeyFn: Fn(&Source::Item) -> Key,
+      PredFn: Fn(&Source::Item) -> bool,
+      Source: SignalVec,
+      Source::Item: Clone + PartialEq,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let FilterKeyedProj {
+			entries,
+			key_fn,
+			pred_fn,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			let entry = |value: Source::Item| FilterKeyedEntry {
+				key: key_fn(&value),
+				is_visible: pred_fn(&value),
+				value,
+			};
+
+			match op {
+				VecDiff::Replace { values } => {
+					let new_entries: Vec<_> = values.into_iter().map(entry).collect();
+
+					let same_keys = new_entries.len() == entries.len()
+						&& new_entries.iter().zip(entries.iter()).all(|(new, old)| new.key == old.key);
+
+					if same_keys {
+						let mut index = 0;
+
+						for (new, old) in new_entries.iter().zip(entries.iter()) {
+							match (old.is_visible, new.is_visible) {
+								(true, true) => {
+									if new.value != old.value {
+										pending_returns.push_back(VecDiff::UpdateAt { index, value: new.value.clone() });
+									}
+
+									index += 1;
+								},
+
+								(true, false) => {
+									pending_returns.push_back(VecDiff::RemoveAt { index });
+								},
+
+								(false, true) => {
+									pending_returns.push_back(VecDiff::InsertAt { index, value: new.value.clone() });
+									index += 1;
+								},
+
+								(false, false) => {},
+							}
+						}
+					} else {
+						let values = new_entries.iter()
+							.filter(|entry| entry.is_visible)
+							.map(|entry| entry.value.clone())
+							.collect();
+
+						pending_returns.push_back(VecDiff::Replace { values });
+					}
+
+					*entries = new_entries;
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					let new = entry(value);
+
+					if new.is_visible {
+						let index = translate_index(entries, index);
+						pending_returns.push_back(VecDiff::InsertAt { index, value: new.value.clone() });
+					}
+
+					entries.insert(index, new);
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					let new = entry(value);
+					let translated = translate_index(entries, index);
+
+					match (entries[index].is_visible, new.is_visible) {
+						(true, true) => {
+							if new.value != entries[index].value {
+								pending_returns.push_back(VecDiff::UpdateAt { index: translated, value: new.value.clone() });
+							}
+						},
+
+						(true, false) => {
+							pending_returns.push_back(VecDiff::RemoveAt { index: translated });
+						},
+
+						(false, true) => {
+							pending_returns.push_back(VecDiff::InsertAt { index: translated, value: new.value.clone() });
+						},
+
+						(false, false) => {},
+					}
+
+					entries[index] = new;
+				},
+
+				VecDiff::RemoveAt { index } => {
+					if entries[index].is_visible {
+						let index = translate_index(entries, index);
+						pending_returns.push_back(VecDiff::RemoveAt { index });
+					}
+
+					entries.remove(index);
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let is_visible = entries[old_index].is_visible;
+					let old_translated = translate_index(entries, old_index);
+
+					let moved = entries.remove(old_index);
+					entries.insert(new_index, moved);
+
+					let new_translated = translate_index(entries, new_index);
+
+					if is_visible && old_translated != new_translated {
+						pending_returns.push_back(VecDiff::Move {
+							old_index: old_translated,
+							new_index: new_translated,
+						});
+					}
+				},
+
+				VecDiff::Push { value } => {
+					let new = entry(value);
+
+					if new.is_visible {
+						pending_returns.push_back(VecDiff::Push { value: new.value.clone() });
+					}
+
+					entries.push(new);
+				},
+
+				VecDiff::Pop {} => {
+					if let Some(FilterKeyedEntry { is_visible: true, .. }) = entries.pop() {
+						pending_returns.push_back(VecDiff::Pop {});
+					}
+				},
+
+				VecDiff::Clear {} => {
+					if entries.iter().any(|entry| entry.is_visible) {
+						pending_returns.push_back(VecDiff::Clear {});
+					}
+
+					entries.clear();
+				},
+			}
+		}
+	}
+}
+
+#[derive(Debug)]
+struct FilterKeyedEntry<Key, Item> {
+	key: Key,
+	is_visible: bool,
+	value: Item,
+}
+
+fn translate_index<Key, Item>(entries: &[FilterKeyedEntry<Key, Item>], index: usize) -> usize {
+	entries[..index].iter().filter(|entry| entry.is_visible).count()
+}
@@ -0,0 +1,138 @@
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Filters and maps the items of a [`SignalVec`] in a single pass, keeping
+/// every item for which `map_fn` returns `Some`.
+///
+/// Indices in the emitted diffs are translated to positions among the kept
+/// items. An `UpdateAt` that makes an item kept or dropped is emitted as an
+/// `InsertAt` or `RemoveAt` at the translated index.
+#[must_use = "FilterMapCloned does nothing unless polled"]
+#[pin_project(project = FilterMapClonedProj)]
+#[derive(Debug)]
+pub struct FilterMapCloned<Source, MapFn> {
+	is_visible: Vec<bool>,
+	map_fn: MapFn,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Output, Source, MapFn> FilterMapCloned<Source, MapFn>
+where Source: SignalVec,
+      MapFn: FnMut(&Source::Item) -> Option<Output>,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, map_fn: MapFn) -> Self {
+		FilterMapCloned {
+			signal,
+			map_fn,
+
+			is_visible: vec![],
+		}
+	}
+}
+
+impl<Output, Source, MapFn> SignalVec for FilterMapCloned<Source, MapFn>
+where Source: SignalVec,
+      MapFn: FnMut(&Source::Item) -> Option<Output>,
+{
+	type Item = Output;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let FilterMapClonedProj {
+			is_visible,
+			map_fn,
+			mut signal,
+		} = self.project();
+
+		loop {
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			let output = match op {
+				VecDiff::Replace { values } => {
+					let values: Vec<_> = values.iter().map(&mut *map_fn).collect();
+					*is_visible = values.iter().map(Option::is_some).collect();
+
+					Some(VecDiff::Replace { values: values.into_iter().flatten().collect() })
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					let value = map_fn(&value);
+					let translated = translate_index(is_visible, index);
+
+					is_visible.insert(index, value.is_some());
+					value.map(|value| VecDiff::InsertAt { index: translated, value })
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					let value = map_fn(&value);
+					let translated = translate_index(is_visible, index);
+
+					let was_visible = std::mem::replace(&mut is_visible[index], value.is_some());
+
+					match (was_visible, value) {
+						(true, Some(value)) => Some(VecDiff::UpdateAt { index: translated, value }),
+						(true, None) => Some(VecDiff::RemoveAt { index: translated }),
+						(false, Some(value)) => Some(VecDiff::InsertAt { index: translated, value }),
+						(false, None) => None,
+					}
+				},
+
+				VecDiff::RemoveAt { index } => {
+					let translated = translate_index(is_visible, index);
+
+					is_visible.remove(index)
+						.then_some(VecDiff::RemoveAt { index: translated })
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let old_translated = translate_index(is_visible, old_index);
+
+					let moved = is_visible.remove(old_index);
+					is_visible.insert(new_index, moved);
+
+					let new_translated = translate_index(is_visible, new_index);
+
+					(moved && old_translated != new_translated).then_some(VecDiff::Move {
+						old_index: old_translated,
+						new_index: new_translated,
+					})
+				},
+
+				VecDiff::Push { value } => {
+					let value = map_fn(&value);
+
+					is_visible.push(value.is_some());
+					value.map(|value| VecDiff::Push { value })
+				},
+
+				VecDiff::Pop {} => {
+					is_visible.pop()
+						.expect("pop requires an item to be present")
+						.then_some(VecDiff::Pop {})
+				},
+
+				VecDiff::Clear {} => {
+					let had_visible = is_visible.iter().any(|&is_visible| is_visible);
+					is_visible.clear();
+
+					had_visible.then_some(VecDiff::Clear {})
+				},
+			};
+
+			if let Some(output) = output {
+				return Poll::Ready(Some(output));
+			}
+		}
+	}
+}
+
+fn translate_index(is_visible: &[bool], index: usize) -> usize {
+	is_visible[..index].iter().filter(|&&is_visible| is_visible).count()
+}
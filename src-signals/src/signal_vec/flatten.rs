@@ -0,0 +1,315 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+type InnerItem<Source> = <<Source as SignalVec>::Item as SignalVec>::Item;
+
+/// Flattens a [`SignalVec`] of [`SignalVec`]s into a single [`SignalVec`].
+///
+/// Diffs coming from the inner signals are translated into global indices
+/// by tracking the current length of every inner signal; the offset of an
+/// inner signal is the sum of the lengths of all inner signals before it.
+#[must_use = "Flatten does nothing unless polled"]
+#[pin_project(project = FlattenProj)]
+pub struct Flatten<Source>
+where Source: SignalVec,
+      Source::Item: SignalVec,
+{
+	groups: Vec<FlattenGroup<Source::Item>>,
+	pending_returns: VecDeque<VecDiff<InnerItem<Source>>>,
+
+	is_done: bool,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source> Flatten<Source>
+where Source: SignalVec,
+      Source::Item: SignalVec,
+{
+	pub(in crate::signal_vec) fn new(signal: Source) -> Self {
+		Flatten {
+			signal,
+
+			groups: vec![],
+			pending_returns: VecDeque::new(),
+			is_done: false,
+		}
+	}
+}
+
+impl<Source> SignalVec for Flatten<Source>
+where Source: SignalVec,
+      Source::Item: SignalVec,
+{
+	type Item = InnerItem<Source>;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let FlattenProj {
+			groups,
+			pending_returns,
+			is_done,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			if !*is_done {
+				match signal.as_mut().poll_vec_change(cx) {
+					Poll::Ready(Some(op)) => {
+						apply_outer_diff(groups, pending_returns, op);
+						continue;
+					},
+
+					Poll::Ready(None) => *is_done = true,
+					Poll::Pending => {},
+				}
+			}
+
+			let mut inner_done = true;
+			let mut inner_op = None;
+
+			for (index, group) in groups.iter_mut().enumerate() {
+				let inner = match group.signal.as_mut() {
+					Some(inner) => inner,
+					None => continue,
+				};
+
+				match inner.as_mut().poll_vec_change(cx) {
+					Poll::Ready(Some(op)) => {
+						inner_done = false;
+						inner_op = Some((index, op));
+						break;
+					},
+
+					Poll::Ready(None) => group.signal = None,
+					Poll::Pending => inner_done = false,
+				}
+			}
+
+			if let Some((index, op)) = inner_op {
+				apply_inner_diff(groups, pending_returns, index, op);
+				continue;
+			}
+
+			return if *is_done && inner_done {
+				Poll::Ready(None)
+			} else {
+				Poll::Pending
+			};
+		}
+	}
+}
+
+struct FlattenGroup<Inner> {
+	signal: Option<Pin<Box<Inner>>>,
+	len: usize,
+}
+
+impl<Inner> FlattenGroup<Inner> {
+	fn new(signal: Inner) -> Self {
+		FlattenGroup {
+			signal: Some(Box::pin(signal)),
+			len: 0,
+		}
+	}
+}
+
+fn apply_outer_diff<Inner>(
+	groups: &mut Vec<FlattenGroup<Inner>>,
+	pending_returns: &mut VecDeque<VecDiff<Inner::Item>>,
+	op: VecDiff<Inner>,
+)
+where Inner: SignalVec,
+{
+	match op {
+		VecDiff::Replace { values } => {
+			if total_len(groups) > 0 {
+				pending_returns.push_back(VecDiff::Clear {});
+			}
+
+			*groups = values.into_iter().map(FlattenGroup::new).collect();
+		},
+
+		VecDiff::InsertAt { index, value } => {
+			groups.insert(index, FlattenGroup::new(value));
+		},
+
+		VecDiff::UpdateAt { index, value } => {
+			remove_group_items(groups, pending_returns, index);
+			groups[index] = FlattenGroup::new(value);
+		},
+
+		VecDiff::RemoveAt { index } => {
+			remove_group_items(groups, pending_returns, index);
+			groups.remove(index);
+		},
+
+		VecDiff::Move { old_index, new_index } => {
+			let old_offset = offset_of(groups, old_index);
+			let group = groups.remove(old_index);
+			let len = group.len;
+
+			groups.insert(new_index, group);
+			let new_offset = offset_of(groups, new_index);
+
+			if new_offset < old_offset {
+				for item in 0..len {
+					pending_returns.push_back(VecDiff::Move {
+						old_index: old_offset + item,
+						new_index: new_offset + item,
+					});
+				}
+			} else if new_offset > old_offset {
+				for _ in 0..len {
+					pending_returns.push_back(VecDiff::Move {
+						old_index: old_offset,
+						new_index: new_offset + len - 1,
+					});
+				}
+			}
+		},
+
+		VecDiff::Push { value } => {
+			groups.push(FlattenGroup::new(value));
+		},
+
+		VecDiff::Pop {} => {
+			let index = groups.len() - 1;
+
+			remove_group_items(groups, pending_returns, index);
+			groups.pop();
+		},
+
+		VecDiff::Clear {} => {
+			if total_len(groups) > 0 {
+				pending_returns.push_back(VecDiff::Clear {});
+			}
+
+			groups.clear();
+		},
+	}
+}
+
+fn apply_inner_diff<Inner>(
+	groups: &mut [FlattenGroup<Inner>],
+	pending_returns: &mut VecDeque<VecDiff<Inner::Item>>,
+	group_index: usize,
+	op: VecDiff<Inner::Item>,
+)
+where Inner: SignalVec,
+{
+	let offset = offset_of(groups, group_index);
+	let total = total_len(groups);
+	let len = groups[group_index].len;
+
+	match op {
+		VecDiff::Replace { values } => {
+			groups[group_index].len = values.len();
+
+			// this group is the only one holding items, so it can replace the
+			// output wholesale
+			if total == len {
+				pending_returns.push_back(VecDiff::Replace { values });
+				return;
+			}
+
+			for _ in 0..len {
+				pending_returns.push_back(VecDiff::RemoveAt { index: offset });
+			}
+
+			for (index, value) in values.into_iter().enumerate() {
+				pending_returns.push_back(VecDiff::InsertAt { index: offset + index, value });
+			}
+		},
+
+		VecDiff::InsertAt { index, value } => {
+			groups[group_index].len += 1;
+			pending_returns.push_back(VecDiff::InsertAt { index: offset + index, value });
+		},
+
+		VecDiff::UpdateAt { index, value } => {
+			pending_returns.push_back(VecDiff::UpdateAt { index: offset + index, value });
+		},
+
+		VecDiff::RemoveAt { index } => {
+			groups[group_index].len -= 1;
+			pending_returns.push_back(VecDiff::RemoveAt { index: offset + index });
+		},
+
+		VecDiff::Move { old_index, new_index } => {
+			pending_returns.push_back(VecDiff::Move {
+				old_index: offset + old_index,
+				new_index: offset + new_index,
+			});
+		},
+
+		VecDiff::Push { value } => {
+			groups[group_index].len += 1;
+
+			if offset + len == total {
+				pending_returns.push_back(VecDiff::Push { value });
+			} else {
+				pending_returns.push_back(VecDiff::InsertAt { index: offset + len, value });
+			}
+		},
+
+		VecDiff::Pop {} => {
+			groups[group_index].len -= 1;
+
+			if offset + len == total {
+				pending_returns.push_back(VecDiff::Pop {});
+			} else {
+				pending_returns.push_back(VecDiff::RemoveAt { index: offset + len - 1 });
+			}
+		},
+
+		VecDiff::Clear {} => {
+			groups[group_index].len = 0;
+
+			if total == len {
+				if len > 0 {
+					pending_returns.push_back(VecDiff::Clear {});
+				}
+
+				return;
+			}
+
+			for _ in 0..len {
+				pending_returns.push_back(VecDiff::RemoveAt { index: offset });
+			}
+		},
+	}
+}
+
+fn remove_group_items<Inner>(
+	groups: &mut [FlattenGroup<Inner>],
+	pending_returns: &mut VecDeque<VecDiff<Inner::Item>>,
+	group_index: usize,
+)
+where Inner: SignalVec,
+{
+	let offset = offset_of(groups, group_index);
+	let len = groups[group_index].len;
+
+	for _ in 0..len {
+		pending_returns.push_back(VecDiff::RemoveAt { index: offset });
+	}
+
+	groups[group_index].len = 0;
+}
+
+fn offset_of<Inner>(groups: &[FlattenGroup<Inner>], group_index: usize) -> usize {
+	groups[..group_index].iter().map(|group| group.len).sum()
+}
+
+fn total_len<Inner>(groups: &[FlattenGroup<Inner>]) -> usize {
+	groups.iter().map(|group| group.len).sum()
+}
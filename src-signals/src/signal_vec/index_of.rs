@@ -0,0 +1,129 @@
+use futures_signals::signal::Signal;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A signal tracking the index of the first item in a [`SignalVec`] matching
+/// a predicate.
+///
+/// Internally, the adapter keeps a mirror of the source vector recording
+/// whether each item matched the predicate, so that every diff can shift the
+/// tracked index without needing to clone the source items.
+#[must_use = "IndexOfSignal does nothing unless polled"]
+#[pin_project(project = IndexOfSignalProj)]
+#[derive(Debug)]
+pub struct IndexOfSignal<Source, PredFn>
+where Source: SignalVec,
+      PredFn: Fn(&Source::Item) -> bool,
+{
+	matches: Vec<bool>,
+	pred_fn: PredFn,
+
+	has_emitted: bool,
+	is_done: bool,
+	last_index: Option<usize>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source, PredFn> IndexOfSignal<Source, PredFn>
+where Source: SignalVec,
+      PredFn: Fn(&Source::Item) -> bool,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, pred_fn: PredFn) -> Self {
+		IndexOfSignal {
+			signal,
+			pred_fn,
+
+			matches: vec![],
+			has_emitted: false,
+			is_done: false,
+			last_index: None,
+		}
+	}
+}
+
+impl<Source, PredFn> Signal for IndexOfSignal<Source, PredFn>
+where Source: SignalVec,
+      PredFn: Fn(&Source::Item) -> bool,
+{
+	type Item = Option<usize>;
+
+	fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		let IndexOfSignalProj {
+			matches,
+			pred_fn,
+			has_emitted,
+			is_done,
+			last_index,
+			mut signal,
+		} = self.project();
+
+		while !*is_done {
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => {
+					*is_done = true;
+					break;
+				},
+
+				Poll::Pending => break,
+			};
+
+			match op {
+				VecDiff::Replace { values } => {
+					*matches = values.iter().map(&*pred_fn).collect();
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					matches.insert(index, pred_fn(&value));
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					matches[index] = pred_fn(&value);
+				},
+
+				VecDiff::RemoveAt { index } => {
+					matches.remove(index);
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let value = matches.remove(old_index);
+					matches.insert(new_index, value);
+				},
+
+				VecDiff::Push { value } => {
+					matches.push(pred_fn(&value));
+				},
+
+				VecDiff::Pop {} => {
+					matches.pop();
+				},
+
+				VecDiff::Clear {} => {
+					matches.clear();
+				},
+			}
+		}
+
+		let index = matches.iter().position(|matched| *matched);
+
+		if !*has_emitted || index != *last_index {
+			log::trace!(
+				"{file}:{line} [{module}::<IndexOfSignal as Signal>::poll_change] tracked index changed from {last_index:?} to {index:?}",
+				file = file!(), line = line!(), module = module_path!(),
+			);
+
+			*has_emitted = true;
+			*last_index = index;
+
+			Poll::Ready(Some(index))
+		} else if *is_done {
+			Poll::Ready(None)
+		} else {
+			Poll::Pending
+		}
+	}
+}
@@ -14,7 +14,7 @@ use std::task::{Poll, Context};
 // left | right
 //
 // order_fn(left, right) -> equal
-// unstable, unimplemented
+// decided by the tiebreak policy, left | right by default
 //
 // order_fn(left, right) -> greater
 // right | left
@@ -28,12 +28,17 @@ where Left: SignalVec,
       Right::Item: Debug + Clone,
       OrderFn: Fn(&Left::Item, &Right::Item) -> Ordering,
 {
+	bias: PriorityBias,
 	items: Vec<MergedVecItem<Left::Item, Right::Item>>,
 	order_fn: OrderFn,
+	tiebreak: Tiebreak,
 
 	pending_ops_left: VecDeque<VecDiff<Left::Item>>,
 	pending_ops_right: VecDeque<VecDiff<Right::Item>>,
 
+	left_done: bool,
+	right_done: bool,
+
 	#[pin]
 	left: Left,
 
@@ -49,16 +54,38 @@ where Left: SignalVec,
       OrderFn: Fn(&Left::Item, &Right::Item) -> Ordering,
 {
 	pub fn new(left: Left, right: Right, order_fn: OrderFn) -> Self {
+		Merge2::with_tiebreak(left, right, order_fn, Tiebreak::default())
+	}
+
+	/// Creates a Merge2 that places items for which `order_fn` returns
+	/// [`Ordering::Equal`] according to `tiebreak`.
+	pub fn with_tiebreak(left: Left, right: Right, order_fn: OrderFn, tiebreak: Tiebreak) -> Self {
 		Merge2 {
 			left,
 			order_fn,
 			right,
+			tiebreak,
 
+			bias: PriorityBias::default(),
 			items: vec![],
 			pending_ops_left: VecDeque::new(),
 			pending_ops_right: VecDeque::new(),
+			left_done: false,
+			right_done: false,
 		}
 	}
+
+	/// Sets which side is emitted first when both sides have an operation
+	/// ready at the same time.
+	///
+	/// With [`PriorityBias::Left`], the default, the left side wins ties
+	/// between operations of the same kind and most collisions between
+	/// different kinds. [`PriorityBias::Right`] inverts every one of those
+	/// decisions.
+	pub fn with_priority(mut self, bias: PriorityBias) -> Self {
+		self.bias = bias;
+		self
+	}
 }
 
 impl<Left, Right, OrderFn> SignalVec for Merge2<Left, Right, OrderFn>
@@ -73,22 +100,26 @@ where Left: SignalVec,
 	// TODO: handle merging two SignalVecs of potentially different types into a single SignalVec that may return either
 	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
 		let Merge2Proj {
+			bias,
 			items,
 			order_fn,
+			tiebreak,
 			pending_ops_left,
 			pending_ops_right,
+			left_done,
+			right_done,
 			mut left,
 			mut right,
 		} = self.project();
 
+		// Both sides are polled on every call unless they have a stashed op or
+		// have already finished. A stashed op is always Ready, so this
+		// combinator can only return Pending after every unfinished side has
+		// returned Pending itself during the same call, and has therefore
+		// registered the current waker.
 		let poll_result = {
-			let next_left = pending_ops_left.pop_front()
-				.map(|item| wrap_poll_result(item))
-				.unwrap_or_else(|| left.as_mut().poll_vec_change(cx));
-
-			let next_right = pending_ops_right.pop_front()
-				.map(|item| wrap_poll_result(item))
-				.unwrap_or_else(|| right.as_mut().poll_vec_change(cx));
+			let next_left = poll_side(pending_ops_left, left_done, left.as_mut(), cx);
+			let next_right = poll_side(pending_ops_right, right_done, right.as_mut(), cx);
 
 			match (next_left, next_right) {
 				(Poll::Pending, Poll::Pending)
@@ -109,13 +140,28 @@ where Left: SignalVec,
 					Poll::Ready(Some(MergedVecItem::Right(right)))
 				},
 
+				// both are replacing their contents, so merge them in one go
+				// instead of emitting one Replace for each side
+				(
+					Poll::Ready(Some(VecDiff::Replace { values: left })),
+					Poll::Ready(Some(VecDiff::Replace { values: right })),
+				) => {
+					log::trace!(
+						"{file}:{line} [{module}::<Merge2 as SignalVec>::poll_vec_change] both sides are Replace",
+						file = file!(), line = line!(), module = module_path!(),
+					);
+
+					*items = merge_values(left, right, order_fn, *tiebreak);
+					return Poll::Ready(Some(VecDiff::Replace { values: items.clone() }));
+				},
+
 				// both are ready, choose one and stash the other for next
 				// iteration
 				(
 					Poll::Ready(Some(left)),
 					Poll::Ready(Some(right)),
 				) => {
-					let priority = get_priority(&left, &right);
+					let priority = get_priority(&left, &right, *bias);
 					log::trace!(
 						"{file}:{line} [{module}::<Merge2 as SignalVec>::poll_vec_change] priority is {priority:?}",
 						file = file!(), line = line!(), module = module_path!(),
@@ -178,11 +224,11 @@ where Left: SignalVec,
 				match new_values {
 					MergedVecItem::Left(values) => values.into_iter().for_each(|item| loop {
 						let other = retained.front();
-						let ordering = other.map(|value| order_fn(&item, value.as_right())).unwrap_or(Ordering::Less);
+						let ordering = other.map(|value| get_ordering(order_fn, &item, value.as_right(), *tiebreak)).unwrap_or(Ordering::Less);
 
 						match ordering {
 							Ordering::Less => break items.push(to_left(item)),
-							Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+							Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 
 							Ordering::Greater => {
 								// we're working on this exact item
@@ -193,7 +239,7 @@ where Left: SignalVec,
 
 					MergedVecItem::Right(values) => values.into_iter().for_each(|item| loop {
 						let other = retained.front();
-						let ordering = other.map(|value| order_fn(value.as_left(), &item)).unwrap_or(Ordering::Greater);
+						let ordering = other.map(|value| get_ordering(order_fn, value.as_left(), &item, *tiebreak)).unwrap_or(Ordering::Greater);
 
 						match ordering {
 							Ordering::Less => {
@@ -201,7 +247,7 @@ where Left: SignalVec,
 								items.push(retained.pop_front().unwrap());
 							}
 
-							Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+							Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 							Ordering::Greater => break items.push(to_right(item)),
 						}
 					}),
@@ -217,7 +263,7 @@ where Left: SignalVec,
 			MergedVecDiff::InsertAt(insert_at) => {
 				let (target, _unused) = get_index(items, &op);
 
-				Some(traverse_insert_into_at(items, insert_at.value().cloned(), target, order_fn))
+				Some(traverse_insert_into_at(items, insert_at.value().cloned(), target, order_fn, *tiebreak))
 			},
 
 			MergedVecDiff::UpdateAt(update_at) => {
@@ -245,7 +291,7 @@ where Left: SignalVec,
 			},
 
 			MergedVecDiff::PushItem(push_item) => {
-				Some(traverse_push(items, push_item.value().cloned(), order_fn))
+				Some(traverse_push(items, push_item.value().cloned(), order_fn, *tiebreak))
 			},
 
 			MergedVecDiff::PopItem(pop_item) => {
@@ -283,6 +329,24 @@ where Left: SignalVec,
 	}
 }
 
+/// Which side of a [`Merge2`] is favoured when both sides have an operation
+/// ready at the same time.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum PriorityBias {
+	#[default]
+	Left,
+	Right,
+}
+
+/// Where a [`Merge2`] places a left and a right item for which its order
+/// function returns [`Ordering::Equal`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Tiebreak {
+	#[default]
+	LeftFirst,
+	RightFirst,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum MergedVecItem<Left, Right>
 where Left: Debug,
@@ -354,6 +418,56 @@ where Left: Debug,
 			MergedVecItem::Right(value) => value,
 		}
 	}
+
+	/// Attempt to unwrap a `MergedVecItem::Left` value, returning the `Right`
+	/// value as an error if `self` is `MergedVecItem::Right`.
+	///
+	/// This is the non-panicking version of [`MergedVecItem::unwrap_left`].
+	/// A `TryFrom<MergedVecItem<Left, Right>>` implementation for `Left` is
+	/// not possible, as `Left` is not local to this crate.
+	pub fn try_into_left(self) -> Result<Left, Right> {
+		match self {
+			MergedVecItem::Left(value) => Ok(value),
+			MergedVecItem::Right(value) => Err(value),
+		}
+	}
+
+	/// Attempt to unwrap a `MergedVecItem::Right` value, returning the `Left`
+	/// value as an error if `self` is `MergedVecItem::Left`.
+	///
+	/// This is the non-panicking version of [`MergedVecItem::unwrap_right`].
+	pub fn try_into_right(self) -> Result<Right, Left> {
+		match self {
+			MergedVecItem::Left(value) => Err(value),
+			MergedVecItem::Right(value) => Ok(value),
+		}
+	}
+}
+
+#[cfg(feature = "either")]
+impl<Left, Right> From<MergedVecItem<Left, Right>> for either::Either<Left, Right>
+where Left: Debug,
+      Right: Debug,
+{
+	fn from(item: MergedVecItem<Left, Right>) -> Self {
+		match item {
+			MergedVecItem::Left(value) => either::Either::Left(value),
+			MergedVecItem::Right(value) => either::Either::Right(value),
+		}
+	}
+}
+
+#[cfg(feature = "either")]
+impl<Left, Right> From<either::Either<Left, Right>> for MergedVecItem<Left, Right>
+where Left: Debug,
+      Right: Debug,
+{
+	fn from(either: either::Either<Left, Right>) -> Self {
+		match either {
+			either::Either::Left(value) => MergedVecItem::Left(value),
+			either::Either::Right(value) => MergedVecItem::Right(value),
+		}
+	}
 }
 
 impl<Left, Right> MergedVecItem<&Left, &Right>
@@ -729,9 +843,90 @@ impl<Left, Right> MergedVecClear<Left, Right> {
 	}
 }
 
+/// Polls one side, returning its stashed operations first.
+pub(in crate::signal_vec) fn poll_side<Side>(
+	pending_ops: &mut VecDeque<VecDiff<Side::Item>>,
+	is_done: &mut bool,
+	signal: Pin<&mut Side>,
+	cx: &mut Context,
+) -> Poll<Option<VecDiff<Side::Item>>>
+where Side: SignalVec,
+{
+	if let Some(op) = pending_ops.pop_front() {
+		return wrap_poll_result(op);
+	}
+
+	// never poll a side again once it has finished
+	if *is_done {
+		return Poll::Ready(None);
+	}
+
+	let poll_result = signal.poll_vec_change(cx);
+
+	if let Poll::Ready(None) = poll_result {
+		*is_done = true;
+	}
+
+	poll_result
+}
+
+/// Compares a left and a right item, breaking ties with `tiebreak` so that the
+/// result is never [`Ordering::Equal`].
+fn get_ordering<Left, Right, OrderFn>(order_fn: &OrderFn, left: &Left, right: &Right, tiebreak: Tiebreak) -> Ordering
+where OrderFn: Fn(&Left, &Right) -> Ordering,
+{
+	match (order_fn(left, right), tiebreak) {
+		(Ordering::Equal, Tiebreak::LeftFirst) => Ordering::Less,
+		(Ordering::Equal, Tiebreak::RightFirst) => Ordering::Greater,
+		(ordering, _) => ordering,
+	}
+}
+
+/// Merges the full contents of both sides, for when both of them are replaced
+/// at the same time.
+fn merge_values<Left, Right, OrderFn>(left: Vec<Left>, right: Vec<Right>, order_fn: &OrderFn, tiebreak: Tiebreak)
+-> Vec<MergedVecItem<Left, Right>>
+where Left: Debug,
+      Right: Debug,
+      OrderFn: Fn(&Left, &Right) -> Ordering,
+{
+	let mut merged = Vec::with_capacity(left.len() + right.len());
+	let mut left = left.into_iter().peekable();
+	let mut right = right.into_iter().peekable();
+
+	loop {
+		let ordering = match (left.peek(), right.peek()) {
+			(Some(left), Some(right)) => get_ordering(order_fn, left, right, tiebreak),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => break merged,
+		};
+
+		match ordering {
+			Ordering::Less => merged.extend(left.next().map(MergedVecItem::Left)),
+			_ => merged.extend(right.next().map(MergedVecItem::Right)),
+		}
+	}
+}
+
 /// Prioritise one operation over the other, based on the order they appear in
 /// [`VecDiff<T>`](futures_signals::signal_vec::VecDiff).
-fn get_priority<'op, Left, Right>(left: &'op VecDiff<Left>, right: &'op VecDiff<Right>)
+fn get_priority<'op, Left, Right>(left: &'op VecDiff<Left>, right: &'op VecDiff<Right>, bias: PriorityBias)
+-> MergedVecItem<&'op VecDiff<Left>, &'op VecDiff<Right>>
+where Left: Debug + Clone,
+      Right: Debug + Clone,
+{
+	match (bias, get_left_priority(left, right)) {
+		(PriorityBias::Left, priority) => priority,
+
+		// the right side wins every collision the left side would have won,
+		// and vice versa
+		(PriorityBias::Right, MergedVecItem::Left(_)) => into_right(right),
+		(PriorityBias::Right, MergedVecItem::Right(_)) => into_left(left),
+	}
+}
+
+fn get_left_priority<'op, Left, Right>(left: &'op VecDiff<Left>, right: &'op VecDiff<Right>)
 -> MergedVecItem<&'op VecDiff<Left>, &'op VecDiff<Right>>
 where Left: Debug + Clone,
       Right: Debug + Clone,
@@ -925,12 +1120,16 @@ where Left: Debug + Clone,
 				None => unreachable!("move item requires old_index to be in bounds"),
 			};
 
-			// find new_index
+			// find new_index. Like VecDiff::Move, the new index is a position
+			// in the collection after the item has been removed from it, so
+			// the item at old_idx is skipped and every later item shifts down
 			let mut new_idx = None;
+			let mut after_last = None;
 			let mut current_new = 0;
 			let target_new = move_item.new_index();
 
-			for (idx, item) in items.into_iter().enumerate() {
+			for (idx, item) in items.into_iter().enumerate().filter(|&(idx, _)| idx != old_idx) {
+				let idx = if idx > old_idx { idx - 1 } else { idx };
 				let is_match = match op.side() {
 					MergedVecItem::Left(()) => matches!(item, MergedVecItem::Left(_)),
 					MergedVecItem::Right(()) => matches!(item, MergedVecItem::Right(_)),
@@ -943,13 +1142,15 @@ where Left: Debug + Clone,
 					}
 
 					current_new += 1;
+					after_last = Some(idx + 1);
 				}
 			}
 
-			let new_idx = match new_idx {
-				Some(index) => index,
-				None => items.len(),
-			};
+			// moving to the end of a side places the item right after the
+			// last remaining item of that side, which is always in bounds
+			// once the item has been removed. If the item is the only one on
+			// its side, it stays where it is.
+			let new_idx = new_idx.or(after_last).unwrap_or(old_idx);
 
 			(old_idx, new_idx)
 		},
@@ -1027,6 +1228,7 @@ fn traverse_insert_into_at<Left, Right, OrderFn>(
 	value: MergedVecItem<Left, Right>,
 	target: usize,
 	order_fn: &mut OrderFn,
+	tiebreak: Tiebreak,
 ) -> VecDiff<MergedVecItem<Left, Right>>
 where Left: Debug + Clone,
       Right: Debug + Clone,
@@ -1079,7 +1281,7 @@ where Left: Debug + Clone,
 						break insert_into_at(items, index + 1, value);
 					},
 
-					MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+					MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 						Ordering::Less => if index > 0 {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] lowering index from {index} to {}", index - 1,
@@ -1097,7 +1299,7 @@ where Left: Debug + Clone,
 							break insert_into_at(items, index, value);
 						},
 
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 						Ordering::Greater => {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {}", index + 1,
@@ -1139,7 +1341,7 @@ where Left: Debug + Clone,
 						break insert_into_at(items, index, value);
 					},
 
-					MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+					MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 						Ordering::Less => {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {index}",
@@ -1148,7 +1350,7 @@ where Left: Debug + Clone,
 
 							break insert_into_at(items, index, value);
 						},
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 						Ordering::Greater => if index < last {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] raising index from {index} to {}", index + 1,
@@ -1209,7 +1411,7 @@ where Left: Debug + Clone,
 					insert_into_at(items, index + 1, value)
 				},
 
-				MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+				MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 					Ordering::Less => {
 						let mut index = index - 1;
 
@@ -1231,7 +1433,7 @@ where Left: Debug + Clone,
 									break insert_into_at(items, index + 1, value);
 								},
 
-								MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+								MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 									Ordering::Less => if index > 0 {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] lowering index from {index} to {}", index - 1,
@@ -1249,7 +1451,7 @@ where Left: Debug + Clone,
 										break insert_into_at(items, index, value);
 									},
 
-									Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+									Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 									Ordering::Greater => {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {}", index + 1,
@@ -1263,7 +1465,7 @@ where Left: Debug + Clone,
 						}
 					},
 
-					Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+					Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 					Ordering::Greater => {
 						let mut index = if index < last {
 							index + 1
@@ -1289,7 +1491,7 @@ where Left: Debug + Clone,
 									break insert_into_at(items, index, value);
 								},
 
-								MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+								MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 									Ordering::Less => {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {index}",
@@ -1299,7 +1501,7 @@ where Left: Debug + Clone,
 										break insert_into_at(items, index, value);
 									},
 
-									Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+									Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 									Ordering::Greater => if index < last {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] raising index from {index} to {}", index + 1,
@@ -1343,7 +1545,7 @@ where Left: Debug + Clone,
 				let existing = &items[index];
 
 				match existing {
-					MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+					MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 						Ordering::Less => {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {}", index + 1,
@@ -1353,7 +1555,7 @@ where Left: Debug + Clone,
 							break insert_into_at(items, index + 1, value);
 						},
 
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 
 						Ordering::Greater => if index > 0 {
 							log::trace!(
@@ -1404,7 +1606,7 @@ where Left: Debug + Clone,
 				let existing = &items[index];
 
 				match existing {
-					MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+					MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 						Ordering::Less => if index < last {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] raising index from {index} to {}", index + 1,
@@ -1422,7 +1624,7 @@ where Left: Debug + Clone,
 							break insert_into_at(items, index + 1, value);
 						}
 
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 						Ordering::Greater => {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {index}",
@@ -1475,7 +1677,7 @@ where Left: Debug + Clone,
 			// but in reverse. I can't be bothered to figure out how to put
 			// this behaviour in its own function right now.
 			match existing {
-				MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+				MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 					Ordering::Less => {
 						let mut index = if index < last {
 							index + 1
@@ -1492,7 +1694,7 @@ where Left: Debug + Clone,
 							let existing = &items[index];
 
 							match existing {
-								MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+								MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 									Ordering::Less => if index < last {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] raising index from {index} to {}", index + 1,
@@ -1510,7 +1712,7 @@ where Left: Debug + Clone,
 										break insert_into_at(items, index + 1, value);
 									},
 
-									Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+									Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 									Ordering::Greater => {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {index}",
@@ -1533,7 +1735,7 @@ where Left: Debug + Clone,
 						}
 					},
 
-					Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+					Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 					Ordering::Greater => {
 						let mut index = index - 1;
 
@@ -1546,7 +1748,7 @@ where Left: Debug + Clone,
 							let existing = &items[index];
 
 							match existing {
-								MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+								MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 									Ordering::Less => {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] inserting {value:?} at {}", index + 1,
@@ -1556,7 +1758,7 @@ where Left: Debug + Clone,
 										break insert_into_at(items, index + 1, value);
 									},
 
-									Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+									Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 									Ordering::Greater => if index > 0 {
 										log::trace!(
 											"{file}:{line} [{module}::traverse_insert_into_at] lowering index from {index} to {}", index - 1,
@@ -1605,10 +1807,11 @@ fn traverse_push<Left, Right, OrderFn>(
 	items: &mut Vec<MergedVecItem<Left, Right>>,
 	value: MergedVecItem<Left, Right>,
 	order_fn: &mut OrderFn,
+	tiebreak: Tiebreak,
 ) -> VecDiff<MergedVecItem<Left, Right>>
 where Left: Debug + Clone,
       Right: Debug + Clone,
-	  OrderFn: FnMut(&Left, &Right) -> Ordering,
+	  OrderFn: Fn(&Left, &Right) -> Ordering,
 {
 	if items.len() == 0 {
 		log::trace!(
@@ -1665,7 +1868,7 @@ where Left: Debug + Clone,
 						break insert_into_at(items, index + 1, value);
 					},
 
-					MergedVecItem::Right(existing) => match order_fn(value.as_left(), existing) {
+					MergedVecItem::Right(existing) => match get_ordering(order_fn, value.as_left(), existing, tiebreak) {
 						Ordering::Less => if index > 0 {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_push] lowering index from {index} to {}", index - 1,
@@ -1683,7 +1886,7 @@ where Left: Debug + Clone,
 							break insert_into_at(items, index, value);
 						},
 
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 
 						Ordering::Greater => if index < last {
 							log::trace!(
@@ -1735,7 +1938,7 @@ where Left: Debug + Clone,
 				let existing = &items[index];
 
 				match existing {
-					MergedVecItem::Left(existing) => match order_fn(existing, value.as_right()) {
+					MergedVecItem::Left(existing) => match get_ordering(order_fn, existing, value.as_right(), tiebreak) {
 						Ordering::Less => if index < last {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_push] inserting {value:?} at {}", index + 1,
@@ -1752,7 +1955,7 @@ where Left: Debug + Clone,
 							break push(items, value);
 						},
 
-						Ordering::Equal => unimplemented!("Ordering::Equal has unstable sorting sematics"),
+						Ordering::Equal => unreachable!("ties are broken by the tiebreak policy"),
 						Ordering::Greater => if index > 0 {
 							log::trace!(
 								"{file}:{line} [{module}::traverse_push] lowering index from {index} to {}", index - 1,
@@ -0,0 +1,284 @@
+use crate::signal_vec::merge::poll_side;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Merges any number of [`SignalVec`]s of the same type into one, ordered by
+/// `order_fn`.
+///
+/// Like [`Merge2`](crate::signal_vec::merge::Merge2), the items of each
+/// source keep their relative order, and new items are placed among the items
+/// of the other sources using `order_fn`. Items that compare equal are kept
+/// in the order of their sources, so that the interleaving is stable.
+///
+/// When several sources have an operation ready at the same time, the
+/// operation that [`Merge2`](crate::signal_vec::merge::Merge2) would pick
+/// between two sides is emitted first, with ties going to the source that
+/// comes first.
+#[must_use = "MergeN does nothing unless polled"]
+#[pin_project(project = MergeNProj)]
+#[derive(Debug)]
+pub struct MergeN<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Debug + Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	entries: Vec<MergeNEntry<Source::Item>>,
+	order_fn: OrderFn,
+	sources: Vec<MergeNSource<Source>>,
+}
+
+impl<Source, OrderFn> MergeN<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Debug + Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	pub fn new(sources: Vec<Source>, order_fn: OrderFn) -> Self {
+		let sources = sources.into_iter()
+			.map(|signal| MergeNSource {
+				signal: Box::pin(signal),
+
+				pending_ops: VecDeque::new(),
+				is_done: false,
+			})
+			.collect();
+
+		MergeN {
+			order_fn,
+			sources,
+
+			entries: vec![],
+		}
+	}
+}
+
+impl<Source, OrderFn> SignalVec for MergeN<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Debug + Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let MergeNProj {
+			entries,
+			order_fn,
+			sources,
+		} = self.project();
+
+		// Every source is polled on every call unless it has a stashed op or
+		// has already finished, for the same reason as in Merge2.
+		let mut polls: Vec<_> = sources.iter_mut()
+			.map(|source| poll_side(&mut source.pending_ops, &mut source.is_done, source.signal.as_mut(), cx))
+			.collect();
+
+		let mut chosen: Option<(usize, u8)> = None;
+
+		for (index, poll) in polls.iter().enumerate() {
+			if let Poll::Ready(Some(op)) = poll {
+				let rank = get_rank(op);
+
+				if chosen.is_none_or(|(_, best)| rank > best) {
+					chosen = Some((index, rank));
+				}
+			}
+		}
+
+		let source = match chosen {
+			Some((source, _)) => source,
+			None if polls.iter().all(|poll| matches!(poll, Poll::Ready(None))) => return Poll::Ready(None),
+			None => return Poll::Pending,
+		};
+
+		// stash every other op for the next iteration
+		for (index, poll) in polls.iter_mut().enumerate() {
+			if index == source {
+				continue;
+			}
+
+			if let Poll::Ready(Some(op)) = poll {
+				sources[index].pending_ops.push_front(op.clone());
+			}
+		}
+
+		let op = match &mut polls[source] {
+			Poll::Ready(op) => op.take().expect("chosen source should have an op ready"),
+			Poll::Pending => unreachable!("chosen source should be ready"),
+		};
+
+		log::trace!(
+			"{file}:{line} [{module}::<MergeN as SignalVec>::poll_vec_change] op is {op:?} from source {source}",
+			file = file!(), line = line!(), module = module_path!(),
+		);
+
+		let diff = match op {
+			VecDiff::Replace { values } => {
+				entries.retain(|entry| entry.source != source);
+
+				let mut start = 0;
+
+				for value in values {
+					let index = get_position(entries, source, &value, start, entries.len(), order_fn);
+					entries.insert(index, MergeNEntry { source, value });
+
+					start = index + 1;
+				}
+
+				VecDiff::Replace { values: get_values(entries) }
+			},
+
+			VecDiff::InsertAt { index, value } => {
+				let start = match index {
+					0 => 0,
+					index => get_nth_index(entries, source, index - 1)
+						.expect("insert requires index to be in bounds") + 1,
+				};
+
+				let end = get_nth_index(entries, source, index).unwrap_or(entries.len());
+				let index = get_position(entries, source, &value, start, end, order_fn);
+
+				entries.insert(index, MergeNEntry { source, value: value.clone() });
+				VecDiff::InsertAt { index, value }
+			},
+
+			VecDiff::UpdateAt { index, value } => {
+				let index = get_nth_index(entries, source, index).expect("update requires index to be in bounds");
+
+				entries[index].value = value.clone();
+				VecDiff::UpdateAt { index, value }
+			},
+
+			VecDiff::RemoveAt { index } => {
+				let index = get_nth_index(entries, source, index).expect("remove requires index to be in bounds");
+
+				entries.remove(index);
+				VecDiff::RemoveAt { index }
+			},
+
+			VecDiff::Move { old_index, new_index } => {
+				let old_index = get_nth_index(entries, source, old_index)
+					.expect("move item requires old_index to be in bounds");
+
+				let moved = entries.remove(old_index);
+
+				// like in Merge2, moving to the end of a source places the
+				// item right after the last remaining item of that source
+				let new_index = get_nth_index(entries, source, new_index)
+					.or_else(|| get_last_index(entries, source).map(|index| index + 1))
+					.unwrap_or(old_index);
+
+				entries.insert(new_index, moved);
+				VecDiff::Move { old_index, new_index }
+			},
+
+			VecDiff::Push { value } => {
+				let start = get_last_index(entries, source).map_or(0, |index| index + 1);
+				let index = get_position(entries, source, &value, start, entries.len(), order_fn);
+
+				entries.insert(index, MergeNEntry { source, value: value.clone() });
+
+				if index == entries.len() - 1 {
+					VecDiff::Push { value }
+				} else {
+					VecDiff::InsertAt { index, value }
+				}
+			},
+
+			VecDiff::Pop {} => {
+				let index = get_last_index(entries, source).expect("item is guaranteed to exist");
+				entries.remove(index);
+
+				if index == entries.len() {
+					VecDiff::Pop {}
+				} else {
+					VecDiff::RemoveAt { index }
+				}
+			},
+
+			VecDiff::Clear {} => {
+				entries.retain(|entry| entry.source != source);
+
+				if entries.is_empty() {
+					VecDiff::Clear {}
+				} else {
+					VecDiff::Replace { values: get_values(entries) }
+				}
+			},
+		};
+
+		Poll::Ready(Some(diff))
+	}
+}
+
+#[derive(Debug)]
+struct MergeNEntry<Item> {
+	source: usize,
+	value: Item,
+}
+
+#[derive(Debug)]
+struct MergeNSource<Source>
+where Source: SignalVec,
+{
+	pending_ops: VecDeque<VecDiff<Source::Item>>,
+	is_done: bool,
+	signal: Pin<Box<Source>>,
+}
+
+/// Ranks operations in the order Merge2 picks between two sides.
+fn get_rank<Item>(op: &VecDiff<Item>) -> u8 {
+	match op {
+		VecDiff::Clear {} => 7,
+		VecDiff::Replace { .. } => 6,
+		VecDiff::InsertAt { .. } => 5,
+		VecDiff::UpdateAt { .. } => 4,
+		VecDiff::RemoveAt { .. } => 3,
+		VecDiff::Move { .. } => 2,
+		VecDiff::Push { .. } => 1,
+		VecDiff::Pop {} => 0,
+	}
+}
+
+/// The position of the `n`th item of `source` in the merged output.
+fn get_nth_index<Item>(entries: &[MergeNEntry<Item>], source: usize, n: usize) -> Option<usize> {
+	entries.iter()
+		.enumerate()
+		.filter(|(_, entry)| entry.source == source)
+		.nth(n)
+		.map(|(index, _)| index)
+}
+
+fn get_last_index<Item>(entries: &[MergeNEntry<Item>], source: usize) -> Option<usize> {
+	entries.iter().rposition(|entry| entry.source == source)
+}
+
+/// Finds where a new item of `source` goes in `entries[start..end]`, which only
+/// holds items of other sources.
+fn get_position<Item, OrderFn>(
+	entries: &[MergeNEntry<Item>],
+	source: usize,
+	value: &Item,
+	start: usize,
+	end: usize,
+	order_fn: &OrderFn,
+) -> usize
+where OrderFn: Fn(&Item, &Item) -> Ordering,
+{
+	entries[start..end].iter()
+		.position(|entry| match order_fn(value, &entry.value) {
+			Ordering::Less => true,
+			Ordering::Equal => source < entry.source,
+			Ordering::Greater => false,
+		})
+		.map_or(end, |offset| start + offset)
+}
+
+fn get_values<Item>(entries: &[MergeNEntry<Item>]) -> Vec<Item>
+where Item: Clone,
+{
+	entries.iter().map(|entry| entry.value.clone()).collect()
+}
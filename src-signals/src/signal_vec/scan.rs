@@ -0,0 +1,95 @@
+use futures_signals::signal::Signal;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A signal folding every diff of a [`SignalVec`] into an accumulated state.
+///
+/// The fold function receives the state, the diff, and the items of the
+/// source as they were *before* the diff was applied, so that diffs which
+/// carry no value (such as `RemoveAt` or `Pop`) can still look up what they
+/// removed.
+#[must_use = "Scan does nothing unless polled"]
+#[pin_project(project = ScanProj)]
+#[derive(Debug)]
+pub struct Scan<Source, State, ScanFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      State: Clone,
+      ScanFn: FnMut(&mut State, &VecDiff<Source::Item>, &[Source::Item]),
+{
+	items: Vec<Source::Item>,
+	scan_fn: ScanFn,
+	state: State,
+
+	has_emitted: bool,
+	is_done: bool,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source, State, ScanFn> Scan<Source, State, ScanFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      State: Clone,
+      ScanFn: FnMut(&mut State, &VecDiff<Source::Item>, &[Source::Item]),
+{
+	pub(in crate::signal_vec) fn new(signal: Source, initial: State, scan_fn: ScanFn) -> Self {
+		Scan {
+			signal,
+			scan_fn,
+
+			items: vec![],
+			state: initial,
+			has_emitted: false,
+			is_done: false,
+		}
+	}
+}
+
+impl<Source, State, ScanFn> Signal for Scan<Source, State, ScanFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      State: Clone,
+      ScanFn: FnMut(&mut State, &VecDiff<Source::Item>, &[Source::Item]),
+{
+	type Item = State;
+
+	fn poll_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		let ScanProj {
+			items,
+			scan_fn,
+			state,
+			has_emitted,
+			is_done,
+			mut signal,
+		} = self.project();
+
+		let mut changed = false;
+
+		while !*is_done {
+			match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => {
+					scan_fn(state, &op, items);
+					op.apply_to_vec(items);
+
+					changed = true;
+				},
+
+				Poll::Ready(None) => *is_done = true,
+				Poll::Pending => break,
+			}
+		}
+
+		if changed || !*has_emitted {
+			*has_emitted = true;
+			Poll::Ready(Some(state.clone()))
+		} else if *is_done {
+			Poll::Ready(None)
+		} else {
+			Poll::Pending
+		}
+	}
+}
@@ -0,0 +1,234 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::{BTreeSet, VecDeque};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Sorts the items of a [`SignalVec`] into sections, emitting a header in
+/// front of the items of every non-empty section.
+///
+/// Sections are ordered by their [`Ord`] implementation, and items keep their
+/// relative source order within a section. An update which changes the
+/// section of an item is emitted as a `RemoveAt` from the old section followed
+/// by an `InsertAt` into the new one, along with any headers that appear or
+/// disappear as a result.
+#[must_use = "Sectioned does nothing unless polled"]
+#[pin_project(project = SectionedProj)]
+#[derive(Debug)]
+pub struct Sectioned<Section, SectionFn, Source>
+where Section: Ord + Clone,
+      SectionFn: Fn(&Source::Item) -> Section,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	entries: Vec<(Section, Source::Item)>,
+	section_fn: SectionFn,
+	pending_returns: VecDeque<VecDiff<SectionedItem<Section, Source::Item>>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Section, SectionFn, Source> Sectioned<Section, SectionFn, Source>
+where Section: Ord + Clone,
+      SectionFn: Fn(&Source::Item) -> Section,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, section_fn: SectionFn) -> Self {
+		Sectioned {
+			signal,
+			section_fn,
+
+			entries: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Section, SectionFn, Source> SignalVec for Sectioned<Section, SectionFn, Source>
+where Section: Ord + Clone,
+      SectionFn: Fn(&Source::Item) -> Section,
+      Source: SignalVec,
+      Source::Item: Clone,
+{
+	type Item = SectionedItem<Section, Source::Item>;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let SectionedProj {
+			entries,
+			section_fn,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			match op {
+				VecDiff::Replace { values } => {
+					*entries = values.into_iter()
+						.map(|value| (section_fn(&value), value))
+						.collect();
+
+					pending_returns.push_back(VecDiff::Replace { values: build_output(entries) });
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					insert_entry(entries, pending_returns, index, (section_fn(&value), value));
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					let section = section_fn(&value);
+
+					if entries[index].0 == section {
+						let output_index = item_index(entries, index);
+
+						entries[index].1 = value.clone();
+						pending_returns.push_back(VecDiff::UpdateAt { index: output_index, value: SectionedItem::Item(value) });
+					} else {
+						remove_entry(entries, pending_returns, index);
+						insert_entry(entries, pending_returns, index, (section, value));
+					}
+				},
+
+				VecDiff::RemoveAt { index } => {
+					remove_entry(entries, pending_returns, index);
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let entry = remove_entry(entries, pending_returns, old_index);
+					insert_entry(entries, pending_returns, new_index, entry);
+				},
+
+				VecDiff::Push { value } => {
+					let index = entries.len();
+					insert_entry(entries, pending_returns, index, (section_fn(&value), value));
+				},
+
+				VecDiff::Pop {} => {
+					let index = entries.len() - 1;
+					remove_entry(entries, pending_returns, index);
+				},
+
+				VecDiff::Clear {} => {
+					entries.clear();
+					pending_returns.push_back(VecDiff::Clear {});
+				},
+			}
+		}
+	}
+}
+
+/// An item emitted by [`Sectioned`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SectionedItem<Section, Item> {
+	Header(Section),
+	Item(Item),
+}
+
+fn build_output<Section, Item>(entries: &[(Section, Item)]) -> Vec<SectionedItem<Section, Item>>
+where Section: Ord + Clone,
+      Item: Clone,
+{
+	let sections: BTreeSet<&Section> = entries.iter().map(|(section, _)| section).collect();
+	let mut output = Vec::with_capacity(entries.len() + sections.len());
+
+	for section in sections {
+		output.push(SectionedItem::Header(section.clone()));
+
+		entries.iter()
+			.filter(|(other, _)| other == section)
+			.for_each(|(_, value)| output.push(SectionedItem::Item(value.clone())));
+	}
+
+	output
+}
+
+/// Returns the output index of the header for `section`, whether or not the
+/// section currently has a header.
+fn header_index<Section, Item>(entries: &[(Section, Item)], section: &Section) -> usize
+where Section: Ord,
+{
+	let before: Vec<_> = entries.iter()
+		.filter(|(other, _)| other < section)
+		.map(|(other, _)| other)
+		.collect();
+
+	let headers = before.iter().collect::<BTreeSet<_>>().len();
+
+	before.len() + headers
+}
+
+/// Returns the output index of the item at `index` in the source.
+fn item_index<Section, Item>(entries: &[(Section, Item)], index: usize) -> usize
+where Section: Ord,
+{
+	let section = &entries[index].0;
+	let offset = entries[..index].iter().filter(|(other, _)| other == section).count();
+
+	header_index(entries, section) + 1 + offset
+}
+
+fn has_section<Section, Item>(entries: &[(Section, Item)], section: &Section) -> bool
+where Section: Ord,
+{
+	entries.iter().any(|(other, _)| other == section)
+}
+
+fn insert_entry<Section, Item>(
+	entries: &mut Vec<(Section, Item)>,
+	pending_returns: &mut VecDeque<VecDiff<SectionedItem<Section, Item>>>,
+	index: usize,
+	entry: (Section, Item),
+)
+where Section: Ord + Clone,
+      Item: Clone,
+{
+	let is_new_section = !has_section(entries, &entry.0);
+
+	entries.insert(index, entry);
+
+	let (section, value) = &entries[index];
+
+	if is_new_section {
+		pending_returns.push_back(VecDiff::InsertAt {
+			index: header_index(entries, section),
+			value: SectionedItem::Header(section.clone()),
+		});
+	}
+
+	pending_returns.push_back(VecDiff::InsertAt {
+		index: item_index(entries, index),
+		value: SectionedItem::Item(value.clone()),
+	});
+}
+
+fn remove_entry<Section, Item>(
+	entries: &mut Vec<(Section, Item)>,
+	pending_returns: &mut VecDeque<VecDiff<SectionedItem<Section, Item>>>,
+	index: usize,
+) -> (Section, Item)
+where Section: Ord,
+{
+	let output_index = item_index(entries, index);
+	let entry = entries.remove(index);
+
+	pending_returns.push_back(VecDiff::RemoveAt { index: output_index });
+
+	// the section is now empty; remove its header as well
+	if !has_section(entries, &entry.0) {
+		pending_returns.push_back(VecDiff::RemoveAt { index: output_index - 1 });
+	}
+
+	entry
+}
@@ -0,0 +1,166 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Windows a [`SignalVec`] to everything after its first `count` items.
+///
+/// A mirror of the source is kept so that items crossing the window boundary
+/// can be emitted; e.g. an insert before the boundary pushes the last skipped
+/// item into the window and emits an `InsertAt` for it.
+#[must_use = "Skip does nothing unless polled"]
+#[pin_project(project = SkipProj)]
+#[derive(Debug)]
+pub struct Skip<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	count: usize,
+	items: Vec<Source::Item>,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source> Skip<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, count: usize) -> Self {
+		Skip {
+			count,
+			signal,
+
+			items: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Source> SignalVec for Skip<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let SkipProj {
+			count,
+			items,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		let count = *count;
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			let len = items.len();
+
+			match op {
+				VecDiff::Replace { values } => {
+					let visible = values.iter().skip(count).cloned().collect();
+					*items = values;
+
+					pending_returns.push_back(VecDiff::Replace { values: visible });
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					items.insert(index, value.clone());
+
+					if index >= count {
+						pending_returns.push_back(VecDiff::InsertAt { index: index - count, value });
+					} else if len >= count {
+						// the last skipped item is pushed into the window
+						pending_returns.push_back(VecDiff::InsertAt { index: 0, value: items[count].clone() });
+					}
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					items[index] = value.clone();
+
+					if index >= count {
+						pending_returns.push_back(VecDiff::UpdateAt { index: index - count, value });
+					}
+				},
+
+				VecDiff::RemoveAt { index } => {
+					items.remove(index);
+
+					if index >= count {
+						pending_returns.push_back(VecDiff::RemoveAt { index: index - count });
+					} else if len > count {
+						// the first visible item is pulled out of the window
+						pending_returns.push_back(VecDiff::RemoveAt { index: 0 });
+					}
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let value = items.remove(old_index);
+					items.insert(new_index, value.clone());
+
+					match (old_index >= count, new_index >= count) {
+						(true, true) => {
+							pending_returns.push_back(VecDiff::Move {
+								old_index: old_index - count,
+								new_index: new_index - count,
+							});
+						},
+
+						// the item leaves the window and the last skipped item
+						// takes its place at the start
+						(true, false) => {
+							pending_returns.push_back(VecDiff::RemoveAt { index: old_index - count });
+							pending_returns.push_back(VecDiff::InsertAt { index: 0, value: items[count].clone() });
+						},
+
+						// the item enters the window and pulls the first visible
+						// item out
+						(false, true) => {
+							pending_returns.push_back(VecDiff::RemoveAt { index: 0 });
+							pending_returns.push_back(VecDiff::InsertAt { index: new_index - count, value });
+						},
+
+						(false, false) => {},
+					}
+				},
+
+				VecDiff::Push { value } => {
+					items.push(value.clone());
+
+					if len >= count {
+						pending_returns.push_back(VecDiff::Push { value });
+					}
+				},
+
+				VecDiff::Pop {} => {
+					items.pop();
+
+					if len > count {
+						pending_returns.push_back(VecDiff::Pop {});
+					}
+				},
+
+				VecDiff::Clear {} => {
+					items.clear();
+
+					if len > count {
+						pending_returns.push_back(VecDiff::Clear {});
+					}
+				},
+			}
+		}
+	}
+}
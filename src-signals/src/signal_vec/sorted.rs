@@ -0,0 +1,241 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Inverts `order_fn`, so that items it would place first are placed last.
+///
+/// Works for any ordering function taking two sides, such as the one given to
+/// [`Merge2`](crate::signal_vec::merge::Merge2) or to
+/// [`SortedVecSignal`].
+///
+/// # Example
+/// ```
+/// use snowcat_signals::signal_vec::sorted::reverse;
+///
+/// let newest_first = reverse(|left: &u64, right: &u64| left.cmp(right));
+///
+/// let mut timestamps = vec![3, 1, 2];
+/// timestamps.sort_by(|left, right| newest_first(left, right));
+///
+/// assert_eq!(timestamps, vec![3, 2, 1]);
+/// ```
+pub fn reverse<Left, Right, OrderFn>(order_fn: OrderFn) -> impl Fn(&Left, &Right) -> Ordering
+where OrderFn: Fn(&Left, &Right) -> Ordering,
+{
+	move |left, right| order_fn(left, right).reverse()
+}
+
+/// Keeps the items of a [`SignalVec`] sorted by `order_fn`.
+///
+/// Items that compare equal keep their relative order in the source. An
+/// `UpdateAt` that changes where an item belongs is emitted as an `UpdateAt`
+/// of the item in place, followed by a `Move` to its new position.
+#[must_use = "SortedVecSignal does nothing unless polled"]
+#[pin_project(project = SortedVecSignalProj)]
+#[derive(Debug)]
+pub struct SortedVecSignal<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	/// The items, in source order.
+	items: Vec<Source::Item>,
+
+	/// The source indices of the items, in sorted order.
+	order: Vec<usize>,
+
+	order_fn: OrderFn,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source, OrderFn> SortedVecSignal<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, order_fn: OrderFn) -> Self {
+		SortedVecSignal {
+			signal,
+			order_fn,
+
+			items: vec![],
+			order: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Source, OrderFn> SignalVec for SortedVecSignal<Source, OrderFn>
+where Source: SignalVec,
+      Source::Item: Clone,
+      OrderFn: Fn(&Source::Item, &Source::Item) -> Ordering,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let SortedVecSignalProj {
+			items,
+			order,
+			order_fn,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			match op {
+				VecDiff::Replace { values } => {
+					*items = values;
+					*order = (0..items.len()).collect();
+
+					// sort_by is stable, so equal items keep their source order
+					order.sort_by(|&left, &right| order_fn(&items[left], &items[right]));
+
+					let values = order.iter().map(|&index| items[index].clone()).collect();
+					pending_returns.push_back(VecDiff::Replace { values });
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					let position = insert_at(items, order, index, value.clone(), order_fn);
+					pending_returns.push_back(VecDiff::InsertAt { index: position, value });
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					let old_position = get_position(order, index);
+					order.remove(old_position);
+
+					items[index] = value.clone();
+
+					let new_position = find_position(items, order, index, order_fn);
+					order.insert(new_position, index);
+
+					pending_returns.push_back(VecDiff::UpdateAt { index: old_position, value });
+
+					if old_position != new_position {
+						pending_returns.push_back(VecDiff::Move { old_index: old_position, new_index: new_position });
+					}
+				},
+
+				VecDiff::RemoveAt { index } => {
+					let position = remove_at(items, order, index);
+
+					if position == order.len() {
+						pending_returns.push_back(VecDiff::Pop {});
+					} else {
+						pending_returns.push_back(VecDiff::RemoveAt { index: position });
+					}
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					// moving an item can only change its place among the items
+					// it compares equal to
+					let value = items[old_index].clone();
+
+					let old_position = remove_at(items, order, old_index);
+					let new_position = insert_at(items, order, new_index, value, order_fn);
+
+					if old_position != new_position {
+						pending_returns.push_back(VecDiff::Move { old_index: old_position, new_index: new_position });
+					}
+				},
+
+				VecDiff::Push { value } => {
+					let position = insert_at(items, order, items.len(), value.clone(), order_fn);
+
+					if position == order.len() - 1 {
+						pending_returns.push_back(VecDiff::Push { value });
+					} else {
+						pending_returns.push_back(VecDiff::InsertAt { index: position, value });
+					}
+				},
+
+				VecDiff::Pop {} => {
+					let position = remove_at(items, order, items.len() - 1);
+
+					if position == order.len() {
+						pending_returns.push_back(VecDiff::Pop {});
+					} else {
+						pending_returns.push_back(VecDiff::RemoveAt { index: position });
+					}
+				},
+
+				VecDiff::Clear {} => {
+					items.clear();
+					order.clear();
+
+					pending_returns.push_back(VecDiff::Clear {});
+				},
+			}
+		}
+	}
+}
+
+/// Inserts an item into the source order, returning its sorted position.
+fn insert_at<Item, OrderFn>(
+	items: &mut Vec<Item>,
+	order: &mut Vec<usize>,
+	index: usize,
+	value: Item,
+	order_fn: &OrderFn,
+) -> usize
+where OrderFn: Fn(&Item, &Item) -> Ordering,
+{
+	items.insert(index, value);
+
+	for other in order.iter_mut().filter(|other| **other >= index) {
+		*other += 1;
+	}
+
+	let position = find_position(items, order, index, order_fn);
+	order.insert(position, index);
+
+	position
+}
+
+/// Removes an item from the source order, returning the sorted position it
+/// had.
+fn remove_at<Item>(items: &mut Vec<Item>, order: &mut Vec<usize>, index: usize) -> usize {
+	let position = get_position(order, index);
+
+	order.remove(position);
+	items.remove(index);
+
+	for other in order.iter_mut().filter(|other| **other > index) {
+		*other -= 1;
+	}
+
+	position
+}
+
+fn get_position(order: &[usize], index: usize) -> usize {
+	order.iter()
+		.position(|&other| other == index)
+		.expect("every item should have a sorted position")
+}
+
+/// Finds the sorted position of `items[index]`, which `order` does not hold.
+fn find_position<Item, OrderFn>(items: &[Item], order: &[usize], index: usize, order_fn: &OrderFn) -> usize
+where OrderFn: Fn(&Item, &Item) -> Ordering,
+{
+	order.partition_point(|&other| match order_fn(&items[other], &items[index]) {
+		Ordering::Less => true,
+		Ordering::Equal => other < index,
+		Ordering::Greater => false,
+	})
+}
@@ -0,0 +1,167 @@
+use crate::signal_vec::wrap_poll_result;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// Windows a [`SignalVec`] to at most its first `count` items.
+///
+/// A mirror of the source is kept so that items crossing the window boundary
+/// can be emitted; e.g. an insert before the boundary pushes the last visible
+/// item out of the window and emits a `RemoveAt` for it.
+#[must_use = "Take does nothing unless polled"]
+#[pin_project(project = TakeProj)]
+#[derive(Debug)]
+pub struct Take<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	count: usize,
+	items: Vec<Source::Item>,
+	pending_returns: VecDeque<VecDiff<Source::Item>>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source> Take<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	pub(in crate::signal_vec) fn new(signal: Source, count: usize) -> Self {
+		Take {
+			count,
+			signal,
+
+			items: vec![],
+			pending_returns: VecDeque::new(),
+		}
+	}
+}
+
+impl<Source> SignalVec for Take<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	type Item = Source::Item;
+
+	fn poll_vec_change(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		let TakeProj {
+			count,
+			items,
+			pending_returns,
+			mut signal,
+		} = self.project();
+
+		let count = *count;
+
+		loop {
+			if let Some(op) = pending_returns.pop_front() {
+				return wrap_poll_result(op);
+			}
+
+			let op = match signal.as_mut().poll_vec_change(cx) {
+				Poll::Ready(Some(op)) => op,
+				Poll::Ready(None) => return Poll::Ready(None),
+				Poll::Pending => return Poll::Pending,
+			};
+
+			let len = items.len();
+
+			match op {
+				VecDiff::Replace { values } => {
+					let visible = values.iter().take(count).cloned().collect();
+					*items = values;
+
+					pending_returns.push_back(VecDiff::Replace { values: visible });
+				},
+
+				VecDiff::InsertAt { index, value } => {
+					items.insert(index, value.clone());
+
+					if index < count {
+						// the last visible item is pushed out of the window
+						if len >= count {
+							pending_returns.push_back(VecDiff::RemoveAt { index: count - 1 });
+						}
+
+						pending_returns.push_back(VecDiff::InsertAt { index, value });
+					}
+				},
+
+				VecDiff::UpdateAt { index, value } => {
+					items[index] = value.clone();
+
+					if index < count {
+						pending_returns.push_back(VecDiff::UpdateAt { index, value });
+					}
+				},
+
+				VecDiff::RemoveAt { index } => {
+					items.remove(index);
+
+					if index < count {
+						pending_returns.push_back(VecDiff::RemoveAt { index });
+
+						// the first hidden item is pulled into the window
+						if len > count {
+							pending_returns.push_back(VecDiff::Push { value: items[count - 1].clone() });
+						}
+					}
+				},
+
+				VecDiff::Move { old_index, new_index } => {
+					let value = items.remove(old_index);
+					items.insert(new_index, value.clone());
+
+					match (old_index < count, new_index < count) {
+						(true, true) => {
+							pending_returns.push_back(VecDiff::Move { old_index, new_index });
+						},
+
+						// the item leaves the window and the first hidden item
+						// takes its place at the end
+						(true, false) => {
+							pending_returns.push_back(VecDiff::RemoveAt { index: old_index });
+							pending_returns.push_back(VecDiff::Push { value: items[count - 1].clone() });
+						},
+
+						// the item enters the window and pushes the last visible
+						// item out
+						(false, true) => {
+							pending_returns.push_back(VecDiff::RemoveAt { index: count - 1 });
+							pending_returns.push_back(VecDiff::InsertAt { index: new_index, value });
+						},
+
+						(false, false) => {},
+					}
+				},
+
+				VecDiff::Push { value } => {
+					items.push(value.clone());
+
+					if len < count {
+						pending_returns.push_back(VecDiff::Push { value });
+					}
+				},
+
+				VecDiff::Pop {} => {
+					items.pop();
+
+					if len <= count {
+						pending_returns.push_back(VecDiff::Pop {});
+					}
+				},
+
+				VecDiff::Clear {} => {
+					items.clear();
+
+					if len > 0 && count > 0 {
+						pending_returns.push_back(VecDiff::Clear {});
+					}
+				},
+			}
+		}
+	}
+}
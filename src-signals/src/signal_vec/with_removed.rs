@@ -0,0 +1,131 @@
+use futures_core::Stream;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use pin_project::pin_project;
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A stream of the diffs of a [`SignalVec`], where every diff that removes or
+/// moves items also carries those items.
+///
+/// `VecDiff::RemoveAt` and `VecDiff::Pop` drop the value they remove, which
+/// makes them impossible to animate on their own. A mirror of the source is
+/// kept so that the value can be looked up before the diff is applied.
+#[must_use = "WithRemoved does nothing unless polled"]
+#[pin_project(project = WithRemovedProj)]
+#[derive(Debug)]
+pub struct WithRemoved<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	items: Vec<Source::Item>,
+
+	#[pin]
+	signal: Source,
+}
+
+impl<Source> WithRemoved<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	pub(in crate::signal_vec) fn new(signal: Source) -> Self {
+		WithRemoved {
+			signal,
+			items: vec![],
+		}
+	}
+}
+
+impl<Source> Stream for WithRemoved<Source>
+where Source: SignalVec,
+      Source::Item: Clone,
+{
+	type Item = VecDiffWithRemoved<Source::Item>;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		let WithRemovedProj {
+			items,
+			mut signal,
+		} = self.project();
+
+		let op = match signal.as_mut().poll_vec_change(cx) {
+			Poll::Ready(Some(op)) => op,
+			Poll::Ready(None) => return Poll::Ready(None),
+			Poll::Pending => return Poll::Pending,
+		};
+
+		Poll::Ready(Some(match op {
+			VecDiff::Replace { values } => {
+				let removed = mem::replace(items, values.clone());
+				VecDiffWithRemoved::Replace { values, removed }
+			},
+
+			VecDiff::InsertAt { index, value } => {
+				items.insert(index, value.clone());
+				VecDiffWithRemoved::InsertAt { index, value }
+			},
+
+			VecDiff::UpdateAt { index, value } => {
+				let removed = mem::replace(&mut items[index], value.clone());
+				VecDiffWithRemoved::UpdateAt { index, value, removed }
+			},
+
+			VecDiff::RemoveAt { index } => {
+				let removed = items.remove(index);
+				VecDiffWithRemoved::RemoveAt { index, removed }
+			},
+
+			VecDiff::Move { old_index, new_index } => {
+				let value = items.remove(old_index);
+				items.insert(new_index, value.clone());
+
+				VecDiffWithRemoved::Move { old_index, new_index, value }
+			},
+
+			VecDiff::Push { value } => {
+				items.push(value.clone());
+				VecDiffWithRemoved::Push { value }
+			},
+
+			VecDiff::Pop {} => {
+				let removed = items.pop().expect("cannot pop from an empty SignalVec");
+				VecDiffWithRemoved::Pop { index: items.len(), removed }
+			},
+
+			VecDiff::Clear {} => {
+				VecDiffWithRemoved::Clear { removed: mem::take(items) }
+			},
+		}))
+	}
+}
+
+/// A [`VecDiff`] carrying the items it removed, replaced or moved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VecDiffWithRemoved<T> {
+	Replace { values: Vec<T>, removed: Vec<T> },
+	InsertAt { index: usize, value: T },
+	UpdateAt { index: usize, value: T, removed: T },
+	RemoveAt { index: usize, removed: T },
+	Move { old_index: usize, new_index: usize, value: T },
+	Push { value: T },
+
+	/// `index` is the position the popped item was at.
+	Pop { index: usize, removed: T },
+	Clear { removed: Vec<T> },
+}
+
+impl<T> VecDiffWithRemoved<T> {
+	/// Drops the removed items, turning this back into a plain [`VecDiff`].
+	pub fn into_diff(self) -> VecDiff<T> {
+		match self {
+			VecDiffWithRemoved::Replace { values, .. } => VecDiff::Replace { values },
+			VecDiffWithRemoved::InsertAt { index, value } => VecDiff::InsertAt { index, value },
+			VecDiffWithRemoved::UpdateAt { index, value, .. } => VecDiff::UpdateAt { index, value },
+			VecDiffWithRemoved::RemoveAt { index, .. } => VecDiff::RemoveAt { index },
+			VecDiffWithRemoved::Move { old_index, new_index, .. } => VecDiff::Move { old_index, new_index },
+			VecDiffWithRemoved::Push { value } => VecDiff::Push { value },
+			VecDiffWithRemoved::Pop { .. } => VecDiff::Pop {},
+			VecDiffWithRemoved::Clear { .. } => VecDiff::Clear {},
+		}
+	}
+}
@@ -0,0 +1,54 @@
+mod util;
+
+use crate::util::{assert_signal_eq, Source};
+use snowcat_signals::signal::SnowcatSignalExt;
+use std::task::Poll;
+
+#[test]
+fn sample_on_trigger() {
+	let value = Source::new(vec![
+		Poll::Ready(1),
+		Poll::Ready(2),
+		Poll::Pending,
+		Poll::Ready(3),
+		Poll::Pending,
+		Poll::Ready(5),
+	]);
+
+	let trigger = Source::new(vec![
+		Poll::Ready(()),
+		Poll::Pending,
+		Poll::Ready(()),
+		Poll::Pending,
+		Poll::Ready(()),
+	]);
+
+	assert_signal_eq(value.sample_on(trigger), vec![
+		Poll::Pending,
+		Poll::Ready(Some(3)),
+		Poll::Pending,
+		Poll::Ready(Some(5)),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn sample_on_trigger_before_value() {
+	let value = Source::new(vec![
+		Poll::Pending,
+		Poll::Ready("draft"),
+	]);
+
+	let trigger = Source::new(vec![
+		Poll::Ready(0),
+		Poll::Ready(1),
+		Poll::Pending,
+		Poll::Ready(2),
+	]);
+
+	assert_signal_eq(value.sample_on(trigger), vec![
+		Poll::Pending,
+		Poll::Ready(Some("draft")),
+		Poll::Ready(None),
+	]);
+}
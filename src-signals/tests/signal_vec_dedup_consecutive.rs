@@ -0,0 +1,71 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[test]
+fn dedup_consecutive_at_start() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2] }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 1 }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 0 }),
+
+		// removes the 1 that is shown, the other one takes its place
+		Poll::Ready(VecDiff::RemoveAt { index: 2 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.dedup_consecutive(|left, right| left == right), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: 0 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: 1 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![0, 1, 2]);
+}
+
+#[test]
+fn dedup_consecutive_at_end() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1] }),
+		Poll::Ready(VecDiff::Push { value: 1 }),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Pop {}),
+		Poll::Ready(VecDiff::Pop {}),
+		Poll::Ready(VecDiff::Push { value: 3 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.dedup_consecutive(|left, right| left == right), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1] })),
+		Poll::Ready(Some(VecDiff::Push { value: 2 })),
+		Poll::Ready(Some(VecDiff::Pop {})),
+		Poll::Ready(Some(VecDiff::Push { value: 3 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 3]);
+}
+
+#[test]
+fn dedup_consecutive_replace_with_duplicates() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 1, 2, 2, 2, 1] }),
+		Poll::Ready(VecDiff::RemoveAt { index: 2 }),
+
+		// joins the last run of 2s
+		Poll::Ready(VecDiff::UpdateAt { index: 4, value: 2 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.dedup_consecutive(|left, right| left == right), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2, 1] })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: 2 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 2, value: 2 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 2]);
+}
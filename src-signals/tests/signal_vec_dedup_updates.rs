@@ -0,0 +1,57 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[test]
+fn dedup_redundant_updates() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3] }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: 2 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: 5 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: 5 }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 0 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: 5 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: 4 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.dedup_updates(), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2, 3] })),
+		Poll::Pending,
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: 5 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: 0 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 0, value: 4 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![4, 1, 5, 3]);
+}
+
+#[test]
+fn dedup_updates_after_moves_and_removals() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: 1 }),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Push { value: 3 }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 2 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: 1 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: 3 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: 2 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.dedup_updates(), vec![
+		Poll::Ready(Some(VecDiff::Push { value: 1 })),
+		Poll::Ready(Some(VecDiff::Push { value: 2 })),
+		Poll::Ready(Some(VecDiff::Push { value: 3 })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 0, value: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![2, 1]);
+}
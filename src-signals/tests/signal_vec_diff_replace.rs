@@ -0,0 +1,71 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+type Entry = (char, u32);
+
+fn key(entry: &Entry) -> char {
+	entry.0
+}
+
+#[test]
+fn diff_replace_keyed() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 1), ('d', 1)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![('b', 1), ('c', 2), ('a', 1), ('e', 1)] }),
+	]);
+
+	let output = assert_signal_vec_eq(source.diff_replace(key), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 1), ('d', 1)] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 3 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 2, value: ('c', 2) })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 3, value: ('e', 1) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('b', 1), ('c', 2), ('a', 1), ('e', 1)]);
+}
+
+#[test]
+fn diff_replace_single_move() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 1)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![('b', 1), ('c', 1), ('a', 1)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![('b', 1), ('c', 1), ('a', 1)] }),
+	]);
+
+	let output = assert_signal_vec_eq(source.diff_replace(key), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 1)] })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('b', 1), ('c', 1), ('a', 1)]);
+}
+
+#[test]
+fn diff_replace_passes_through_other_diffs() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: ('a', 1) }),
+		Poll::Ready(VecDiff::Push { value: ('b', 1) }),
+		Poll::Ready(VecDiff::Replace { values: vec![('b', 1), ('a', 2)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![('c', 1), ('c', 2)] }),
+	]);
+
+	let output = assert_signal_vec_eq(source.diff_replace(key), vec![
+		Poll::Ready(Some(VecDiff::Push { value: ('a', 1) })),
+		Poll::Ready(Some(VecDiff::Push { value: ('b', 1) })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 1 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: ('a', 2) })),
+
+		// duplicate keys cannot be diffed
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('c', 1), ('c', 2)] })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('c', 1), ('c', 2)]);
+}
@@ -0,0 +1,78 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Message {
+	id: u32,
+	is_ad: bool,
+}
+
+fn chat(id: u32) -> Message {
+	Message { id, is_ad: false }
+}
+
+fn ad(id: u32) -> Message {
+	Message { id, is_ad: true }
+}
+
+#[test]
+fn filter_keyed_toggle_middle_item() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![chat(1), chat(2), chat(3)] }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: ad(2) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: chat(2) }),
+	]);
+
+	let filtered = source.filter_keyed(|message| message.id, |message| !message.is_ad);
+
+	let output = assert_signal_vec_eq(filtered, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![chat(1), chat(2), chat(3)] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: chat(2) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![chat(1), chat(2), chat(3)]);
+}
+
+#[test]
+fn filter_keyed_replace_with_same_keys() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![chat(1), ad(2), chat(3), chat(4)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![chat(1), ad(2), ad(3), chat(4)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![chat(1), chat(2), ad(3), chat(4)] }),
+	]);
+
+	let filtered = source.filter_keyed(|message| message.id, |message| !message.is_ad);
+
+	let output = assert_signal_vec_eq(filtered, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![chat(1), chat(3), chat(4)] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: chat(2) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![chat(1), chat(2), chat(4)]);
+}
+
+#[test]
+fn filter_keyed_replace_with_new_keys() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![chat(1), ad(2)] }),
+		Poll::Ready(VecDiff::Replace { values: vec![chat(3), ad(4), chat(5)] }),
+	]);
+
+	let filtered = source.filter_keyed(|message| message.id, |message| !message.is_ad);
+
+	let output = assert_signal_vec_eq(filtered, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![chat(1)] })),
+		Poll::Ready(Some(VecDiff::Replace { values: vec![chat(3), chat(5)] })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![chat(3), chat(5)]);
+}
@@ -0,0 +1,111 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+// even numbers are kept, and halved
+fn halve_even(value: &u32) -> Option<u32> {
+	(value % 2 == 0).then_some(value / 2)
+}
+
+#[test]
+fn filter_map_replace_and_insert() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3, 4] }),
+		Poll::Ready(VecDiff::InsertAt { index: 2, value: 6 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 5 }),
+		Poll::Ready(VecDiff::Push { value: 8 }),
+		Poll::Ready(VecDiff::Push { value: 9 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.filter_map_cloned(halve_even), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: 3 })),
+		Poll::Pending,
+		Poll::Ready(Some(VecDiff::Push { value: 4 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 3, 2, 4]);
+}
+
+#[test]
+fn filter_map_update_changes_visibility() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![2, 3, 4] }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: 5 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: 6 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: 8 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: 7 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.filter_map_cloned(halve_even), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: 3 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 0, value: 4 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![4, 3]);
+}
+
+#[test]
+fn filter_map_remove_and_pop() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![2, 3, 4, 5, 6, 7] }),
+		Poll::Ready(VecDiff::RemoveAt { index: 3 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 2 }),
+		Poll::Ready(VecDiff::Pop {}),
+		Poll::Ready(VecDiff::Pop {}),
+	]);
+
+	let output = assert_signal_vec_eq(source.filter_map_cloned(halve_even), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2, 3] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::Pop {})),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1]);
+}
+
+#[test]
+fn filter_map_move() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![2, 3, 4, 5, 6] }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 4 }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 1 }),
+		Poll::Ready(VecDiff::Move { old_index: 1, new_index: 2 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.filter_map_cloned(halve_even), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2, 3] })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![2, 3, 1]);
+}
+
+#[test]
+fn filter_map_clear() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 3] }),
+		Poll::Ready(VecDiff::Clear {}),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Clear {}),
+	]);
+
+	let output = assert_signal_vec_eq(source.filter_map_cloned(halve_even), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![] })),
+		Poll::Ready(Some(VecDiff::Push { value: 1 })),
+		Poll::Ready(Some(VecDiff::Clear {})),
+		Poll::Ready(None),
+	]);
+
+	assert!(output.is_empty());
+}
@@ -0,0 +1,100 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+type Group = Source<VecDiff<u32>>;
+
+#[test]
+fn flatten_inner_changes_across_groups() {
+	let first: Group = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: 3 }),
+	]);
+
+	let second: Group = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![10] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 11 }),
+	]);
+
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![first, second] }),
+	]);
+
+	let output = assert_signal_vec_eq(source.flatten(), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 2, value: 10 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 2, value: 3 })),
+		Poll::Pending,
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 3 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 3, value: 11 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 2, 3, 11]);
+}
+
+#[test]
+fn flatten_push_and_pop_across_groups() {
+	let first: Group = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: 1 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: 2 }),
+	]);
+
+	let second: Group = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: 10 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Pop {}),
+	]);
+
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: first }),
+		Poll::Ready(VecDiff::Push { value: second }),
+	]);
+
+	let output = assert_signal_vec_eq(source.flatten(), vec![
+		Poll::Ready(Some(VecDiff::Push { value: 1 })),
+		Poll::Ready(Some(VecDiff::Push { value: 10 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: 2 })),
+		Poll::Pending,
+		Poll::Ready(Some(VecDiff::Pop {})),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 2]);
+}
+
+#[test]
+fn flatten_remove_group() {
+	let first: Group = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2] }),
+	]);
+
+	let second: Group = Source::new(vec![
+		Poll::Pending,
+		Poll::Ready(VecDiff::Replace { values: vec![3] }),
+	]);
+
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![first, second] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.flatten(), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Pending,
+		Poll::Ready(Some(VecDiff::Replace { values: vec![3] })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![3]);
+}
@@ -0,0 +1,82 @@
+mod util;
+
+use crate::util::{assert_signal_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[test]
+fn index_of_insert_before_target() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 0 }),
+		Poll::Ready(VecDiff::InsertAt { index: 1, value: 9 }),
+	]);
+
+	let index = source.index_of_signal(|value| *value == 3);
+
+	assert_signal_eq(index, vec![
+		Poll::Ready(Some(Some(2))),
+		Poll::Ready(Some(Some(4))),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn index_of_insert_after_target() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: 4 }),
+		Poll::Ready(VecDiff::InsertAt { index: 3, value: 5 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::InsertAt { index: 1, value: 9 }),
+	]);
+
+	let index = source.index_of_signal(|value| *value == 3);
+
+	assert_signal_eq(index, vec![
+		Poll::Ready(Some(Some(2))),
+		Poll::Pending,
+		Poll::Ready(Some(Some(3))),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn index_of_target_removed() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3, 4] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::RemoveAt { index: 2 }),
+	]);
+
+	let index = source.index_of_signal(|value| *value == 3);
+
+	assert_signal_eq(index, vec![
+		Poll::Ready(Some(Some(2))),
+		Poll::Ready(Some(None)),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn index_of_tracks_first_match() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![3, 1, 3] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Move { old_index: 1, new_index: 0 }),
+	]);
+
+	let index = source.index_of_signal(|value| *value == 3);
+
+	assert_signal_eq(index, vec![
+		Poll::Ready(Some(Some(0))),
+		Poll::Ready(Some(Some(1))),
+		Poll::Ready(Some(Some(0))),
+		Poll::Ready(None),
+	]);
+}
@@ -67,10 +67,6 @@ fn merge_replace_less() {
 	);
 
 	assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect()
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -113,10 +109,6 @@ fn merge_replace_less_equal() {
 	);
 
 	assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect()
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -159,10 +151,6 @@ fn merge_replace_greater() {
 	);
 
 	assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect()
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_right(&notifications[0]),
@@ -205,10 +193,6 @@ fn merge_replace_greater_equal() {
 	);
 
 	assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect()
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_right(&notifications[0]),
@@ -349,10 +333,6 @@ fn merge_update_at() {
 	);
 
 	let output = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -415,10 +395,6 @@ fn merge_remove_at() {
 	);
 
 	let state = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -483,10 +459,6 @@ fn merge_move_item_left() {
 	);
 
 	let output = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -557,10 +529,6 @@ fn merge_move_item_right() {
 	);
 
 	let output = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -688,14 +656,6 @@ fn merge_move_item_interleaved() {
 			item.expect("a non-empty change").apply_to_vec(vec);
 		}
 
-		test_apply(
-			merged.poll_vec_change_unpin(cx),
-			Poll::Ready(Some(VecDiff::Replace {
-				values: messages.iter().map(to_left).collect(),
-			})),
-			&mut output,
-		);
-
 		test_apply(
 			merged.poll_vec_change_unpin(cx),
 			Poll::Ready(Some(VecDiff::Replace {
@@ -1148,10 +1108,6 @@ fn merge_pop_left() {
 	);
 
 	let output = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -1212,10 +1168,6 @@ fn merge_pop_right() {
 	);
 
 	let output = assert_signal_vec_eq(merged, vec![
-		Poll::Ready(Some(VecDiff::Replace {
-			values: messages.iter().map(to_left).collect(),
-		})),
-
 		Poll::Ready(Some(VecDiff::Replace {
 			values: vec![
 				to_left(&messages[0]),
@@ -1297,14 +1249,6 @@ fn merge_pop_all_interleaved() {
 			item.expect("a non-empty change").apply_to_vec(vec);
 		}
 
-		test_apply(
-			merged.poll_vec_change_unpin(cx),
-			Poll::Ready(Some(VecDiff::Replace {
-				values: messages.iter().map(to_left).collect(),
-			})),
-			&mut output,
-		);
-
 		test_apply(
 			merged.poll_vec_change_unpin(cx),
 			Poll::Ready(Some(VecDiff::Replace {
@@ -1491,14 +1435,6 @@ fn merge_pop_all_ordered() {
 			item.expect("a non-empty change").apply_to_vec(vec);
 		}
 
-		test_apply(
-			merged.poll_vec_change_unpin(cx),
-			Poll::Ready(Some(VecDiff::Replace {
-				values: messages.iter().map(to_left).collect(),
-			})),
-			&mut output,
-		);
-
 		test_apply(
 			merged.poll_vec_change_unpin(cx),
 			Poll::Ready(Some(VecDiff::Replace {
@@ -1662,14 +1598,6 @@ fn merge_clear_left_first() {
 		let mut messages_lock = messages_vec.lock_mut();
 		let mut notifications_lock = notifications_vec.lock_mut();
 
-		assert_eq!(
-			Poll::Ready(Some(VecDiff::Replace {
-				values: messages.iter().map(to_left).collect(),
-			})),
-
-			merged.poll_vec_change_unpin(cx),
-		);
-
 		assert_eq!(
 			Poll::Ready(Some(VecDiff::Replace {
 				values: vec![
@@ -1744,14 +1672,6 @@ fn merge_clear_right_first() {
 		let mut messages_lock = messages_vec.lock_mut();
 		let mut notifications_lock = notifications_vec.lock_mut();
 
-		assert_eq!(
-			Poll::Ready(Some(VecDiff::Replace {
-				values: messages.iter().map(to_left).collect(),
-			})),
-
-			merged.poll_vec_change_unpin(cx),
-		);
-
 		assert_eq!(
 			Poll::Ready(Some(VecDiff::Replace {
 				values: vec![
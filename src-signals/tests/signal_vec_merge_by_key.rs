@@ -0,0 +1,57 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::merge::MergedVecItem;
+use std::task::Poll;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Message {
+	text: &'static str,
+	timestamp: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Notification {
+	timestamp: u64,
+}
+
+#[test]
+fn merge_by_key_orders_by_key() {
+	let first = Message { text: "first", timestamp: 10 };
+	let second = Message { text: "second", timestamp: 30 };
+	let joined = Notification { timestamp: 20 };
+	let left = Notification { timestamp: 30 };
+
+	let messages = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![first.clone()] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: second.clone() }),
+	]);
+
+	let notifications = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: joined.clone() }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: left.clone() }),
+	]);
+
+	let merged = messages.merge_by_key(notifications, |message| message.timestamp, |notification| notification.timestamp);
+
+	let output = assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![MergedVecItem::Left(first.clone())] })),
+		Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Right(joined.clone()) })),
+		Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Left(second.clone()) })),
+		Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Right(left.clone()) })),
+		Poll::Ready(None),
+	]);
+
+	// the message and the notification at 30 are equal, and the message goes
+	// first
+	assert_eq!(output, vec![
+		MergedVecItem::Left(first),
+		MergedVecItem::Right(joined),
+		MergedVecItem::Left(second),
+		MergedVecItem::Right(left),
+	]);
+}
@@ -0,0 +1,29 @@
+use snowcat_signals::signal_vec::merge::MergedVecItem;
+
+#[test]
+fn merged_item_try_into_side() {
+	let left: MergedVecItem<u32, &str> = MergedVecItem::Left(1);
+	let right: MergedVecItem<u32, &str> = MergedVecItem::Right("one");
+
+	assert_eq!(left.try_into_left(), Ok(1));
+	assert_eq!(right.try_into_right(), Ok("one"));
+
+	// the wrong side hands back the value it actually holds
+	assert_eq!(left.try_into_right(), Err(1));
+	assert_eq!(right.try_into_left(), Err("one"));
+}
+
+#[cfg(feature = "either")]
+#[test]
+fn merged_item_either_round_trip() {
+	use either::Either;
+
+	let left: MergedVecItem<u32, &str> = MergedVecItem::Left(1);
+	let right: MergedVecItem<u32, &str> = MergedVecItem::Right("one");
+
+	assert_eq!(Either::from(left), Either::Left(1));
+	assert_eq!(Either::from(right), Either::Right("one"));
+
+	assert_eq!(MergedVecItem::from(Either::<u32, &str>::Left(1)), left);
+	assert_eq!(MergedVecItem::from(Either::<u32, &str>::Right("one")), right);
+}
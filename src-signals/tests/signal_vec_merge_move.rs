@@ -0,0 +1,83 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::merge::MergedVecItem;
+use std::cmp::Ordering;
+use std::task::Poll;
+
+fn order(left: &u32, right: &u32) -> Ordering {
+	if left < right {
+		Ordering::Less
+	} else {
+		Ordering::Greater
+	}
+}
+
+#[test]
+fn merge_move_to_end() {
+	let left = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 5] }),
+
+		// to a position equal to the new length of the left side
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 1 }),
+
+		// the last item to the end
+		Poll::Ready(VecDiff::Move { old_index: 1, new_index: 1 }),
+	]);
+
+	let right = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![3] }),
+	]);
+
+	let output = assert_signal_vec_eq(left.merge(right, order), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![
+			MergedVecItem::Left(1),
+			MergedVecItem::Right(3),
+			MergedVecItem::Left(5),
+		] })),
+
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 2, new_index: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![
+		MergedVecItem::Right(3),
+		MergedVecItem::Left(5),
+		MergedVecItem::Left(1),
+	]);
+}
+
+#[test]
+fn merge_move_forward_within_side() {
+	let left = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 6] }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 1 }),
+	]);
+
+	let right = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![4] }),
+	]);
+
+	let output = assert_signal_vec_eq(left.merge(right, order), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![
+			MergedVecItem::Left(1),
+			MergedVecItem::Left(2),
+			MergedVecItem::Right(4),
+			MergedVecItem::Left(6),
+		] })),
+
+		// lands in front of the left item at index 1 once it is removed
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![
+		MergedVecItem::Left(2),
+		MergedVecItem::Right(4),
+		MergedVecItem::Left(1),
+		MergedVecItem::Left(6),
+	]);
+}
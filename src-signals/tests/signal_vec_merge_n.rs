@@ -0,0 +1,167 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, with_noop_context, Source};
+use futures_signals::signal_vec::{MutableVec, SignalVec, SignalVecExt, VecDiff};
+use snowcat_signals::signal_vec::merge_n::MergeN;
+use std::cmp::Ordering;
+use std::task::Poll;
+
+// items are tagged with the source they come from, and ordered by the number
+type Item = (char, u32);
+
+fn merge_sources<S>(sources: Vec<S>) -> MergeN<S, impl Fn(&Item, &Item) -> Ordering>
+where S: SignalVec<Item = Item>,
+{
+	MergeN::new(sources, |left: &Item, right: &Item| left.1.cmp(&right.1))
+}
+
+#[track_caller]
+fn test_apply(actual: Poll<Option<VecDiff<Item>>>, expected: VecDiff<Item>, vec: &mut Vec<Item>) {
+	assert_eq!(actual, Poll::Ready(Some(expected)));
+
+	if let Poll::Ready(Some(change)) = actual {
+		change.apply_to_vec(vec);
+	}
+}
+
+#[test]
+fn merge_n_push_in_order() {
+	let vecs: Vec<MutableVec<Item>> = (0..3).map(|_| MutableVec::new()).collect();
+	let mut merged = merge_sources(vecs.iter().map(|vec| vec.signal_vec_cloned()).collect());
+
+	let mut output = vec![];
+	with_noop_context(|cx| {
+		let mut locks: Vec<_> = vecs.iter().map(|vec| vec.lock_mut()).collect();
+
+		locks[0].push_cloned(('a', 1));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('a', 1) }, &mut output);
+
+		locks[1].push_cloned(('b', 2));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('b', 2) }, &mut output);
+
+		locks[2].push_cloned(('c', 3));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('c', 3) }, &mut output);
+
+		locks[0].push_cloned(('a', 4));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('a', 4) }, &mut output);
+
+		// pushed at the same time; the first source goes first
+		locks[2].push_cloned(('c', 6));
+		locks[0].push_cloned(('a', 5));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('a', 5) }, &mut output);
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('c', 6) }, &mut output);
+
+		locks[1].push_cloned(('b', 7));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('b', 7) }, &mut output);
+
+		locks[2].push_cloned(('c', 9));
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Push { value: ('c', 9) }, &mut output);
+
+		// lands between the items of the other sources
+		locks[0].push_cloned(('a', 8));
+		test_apply(
+			merged.poll_vec_change_unpin(cx),
+			VecDiff::InsertAt { index: 7, value: ('a', 8) },
+			&mut output,
+		);
+
+		assert_eq!(merged.poll_vec_change_unpin(cx), Poll::Pending);
+	});
+
+	assert_eq!(output, vec![
+		('a', 1),
+		('b', 2),
+		('c', 3),
+		('a', 4),
+		('a', 5),
+		('c', 6),
+		('b', 7),
+		('a', 8),
+		('c', 9),
+	]);
+}
+
+#[test]
+fn merge_n_pop_all_interleaved() {
+	let vecs: Vec<MutableVec<Item>> = ['a', 'b', 'c', 'd'].into_iter()
+		.zip(1..)
+		.map(|(tag, first)| MutableVec::new_with_values(vec![(tag, first), (tag, first + 4)]))
+		.collect();
+
+	let mut merged = merge_sources(vecs.iter().map(|vec| vec.signal_vec_cloned()).collect());
+
+	let mut output = vec![];
+	with_noop_context(|cx| {
+		let mut locks: Vec<_> = vecs.iter().map(|vec| vec.lock_mut()).collect();
+
+		test_apply(
+			merged.poll_vec_change_unpin(cx),
+			VecDiff::Replace { values: vec![('a', 1), ('a', 5)] },
+			&mut output,
+		);
+
+		test_apply(
+			merged.poll_vec_change_unpin(cx),
+			VecDiff::Replace { values: vec![('a', 1), ('b', 2), ('a', 5), ('b', 6)] },
+			&mut output,
+		);
+
+		test_apply(
+			merged.poll_vec_change_unpin(cx),
+			VecDiff::Replace { values: vec![('a', 1), ('b', 2), ('c', 3), ('a', 5), ('b', 6), ('c', 7)] },
+			&mut output,
+		);
+
+		test_apply(
+			merged.poll_vec_change_unpin(cx),
+			VecDiff::Replace {
+				values: vec![('a', 1), ('b', 2), ('c', 3), ('d', 4), ('a', 5), ('b', 6), ('c', 7), ('d', 8)],
+			},
+			&mut output,
+		);
+
+		locks[3].pop();
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Pop {}, &mut output);
+
+		locks[2].pop();
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Pop {}, &mut output);
+
+		locks[0].pop();
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::RemoveAt { index: 4 }, &mut output);
+
+		locks[1].pop();
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Pop {}, &mut output);
+
+		// popped at the same time; handled in the order of the sources
+		for lock in locks.iter_mut().rev() {
+			lock.pop();
+		}
+
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::RemoveAt { index: 0 }, &mut output);
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::RemoveAt { index: 0 }, &mut output);
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::RemoveAt { index: 0 }, &mut output);
+		test_apply(merged.poll_vec_change_unpin(cx), VecDiff::Pop {}, &mut output);
+
+		assert_eq!(merged.poll_vec_change_unpin(cx), Poll::Pending);
+	});
+
+	assert!(output.is_empty());
+}
+
+#[test]
+fn merge_n_equal_items_keep_source_order() {
+	let sources = vec![
+		Source::new(vec![Poll::Pending, Poll::Ready(VecDiff::Replace { values: vec![('a', 1)] })]),
+		Source::new(vec![Poll::Pending, Poll::Pending, Poll::Ready(VecDiff::Replace { values: vec![('b', 1)] })]),
+		Source::new(vec![Poll::Ready(VecDiff::Replace { values: vec![('c', 1)] })]),
+	];
+
+	let output = assert_signal_vec_eq(merge_sources(sources), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('c', 1)] })),
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('a', 1), ('c', 1)] })),
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 1)] })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('a', 1), ('b', 1), ('c', 1)]);
+}
@@ -0,0 +1,88 @@
+mod util;
+
+use crate::util::{map_poll_vec, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::merge::{MergedVecItem, PriorityBias};
+use std::cmp::Ordering;
+use std::task::Poll;
+
+type Diff = VecDiff<MergedVecItem<u32, u32>>;
+
+fn merge_collision(bias: PriorityBias) -> Vec<Poll<Option<Diff>>> {
+	let left = Source::new(vec![Poll::Ready(VecDiff::Replace { values: vec![1, 3] })]);
+	let right = Source::new(vec![Poll::Ready(VecDiff::Push { value: 2 })]);
+
+	let merged = left
+		.merge(right, |left, right| {
+			if left < right {
+				Ordering::Less
+			} else {
+				Ordering::Greater
+			}
+		})
+		.with_priority(bias);
+
+	map_poll_vec(merged, |_output, change| change)
+}
+
+fn apply_all(changes: Vec<Poll<Option<Diff>>>) -> Vec<MergedVecItem<u32, u32>> {
+	let mut output = vec![];
+
+	changes.into_iter()
+		.filter_map(|change| if let Poll::Ready(Some(change)) = change { Some(change) } else { None })
+		.for_each(|change| change.apply_to_vec(&mut output));
+
+	output
+}
+
+#[test]
+fn merge_left_bias_emits_left_first() {
+	let changes = merge_collision(PriorityBias::Left);
+
+	assert_eq!(changes[0], Poll::Ready(Some(VecDiff::Replace {
+		values: vec![MergedVecItem::Left(1), MergedVecItem::Left(3)],
+	})));
+
+	assert_eq!(apply_all(changes), vec![
+		MergedVecItem::Left(1),
+		MergedVecItem::Right(2),
+		MergedVecItem::Left(3),
+	]);
+}
+
+#[test]
+fn merge_right_bias_emits_right_first() {
+	let changes = merge_collision(PriorityBias::Right);
+
+	assert_eq!(changes[0], Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Right(2) })));
+
+	assert_eq!(apply_all(changes), vec![
+		MergedVecItem::Left(1),
+		MergedVecItem::Right(2),
+		MergedVecItem::Left(3),
+	]);
+}
+
+#[test]
+fn merge_both_replace_emit_once() {
+	for bias in [PriorityBias::Left, PriorityBias::Right] {
+		let left = Source::new(vec![Poll::Ready(VecDiff::Replace { values: vec![1, 4, 5] })]);
+		let right = Source::new(vec![Poll::Ready(VecDiff::Replace { values: vec![2, 3, 6] })]);
+
+		let merged = left.merge(right, |left, right| left.cmp(right)).with_priority(bias);
+
+		assert_eq!(map_poll_vec(merged, |_output, change| change), vec![
+			Poll::Ready(Some(VecDiff::Replace { values: vec![
+				MergedVecItem::Left(1),
+				MergedVecItem::Right(2),
+				MergedVecItem::Right(3),
+				MergedVecItem::Left(4),
+				MergedVecItem::Left(5),
+				MergedVecItem::Right(6),
+			] })),
+
+			Poll::Ready(None),
+		]);
+	}
+}
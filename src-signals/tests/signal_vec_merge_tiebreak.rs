@@ -0,0 +1,116 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::merge::{Merge2, MergedVecItem, Tiebreak};
+use std::cmp::Ordering;
+use std::task::Poll;
+
+// messages and notifications, both timestamped to the second
+type Diff = VecDiff<u32>;
+
+fn merge_timestamps(messages: Vec<Diff>, notifications: Vec<Diff>, tiebreak: Tiebreak)
+-> Merge2<Source<Diff>, Source<Diff>, fn(&u32, &u32) -> Ordering>
+{
+	let messages = Source::new(messages.into_iter().map(Poll::Ready).collect());
+	let notifications = Source::new(notifications.into_iter().map(Poll::Ready).collect());
+
+	let order_fn: fn(&u32, &u32) -> Ordering = u32::cmp;
+	Merge2::with_tiebreak(messages, notifications, order_fn, tiebreak)
+}
+
+#[test]
+fn merge_replace_left_first() {
+	let merged = merge_timestamps(
+		vec![VecDiff::Replace { values: vec![1, 2] }],
+		vec![VecDiff::Replace { values: vec![2, 3] }],
+		Tiebreak::LeftFirst,
+	);
+
+	assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![
+			MergedVecItem::Left(1),
+			MergedVecItem::Left(2),
+			MergedVecItem::Right(2),
+			MergedVecItem::Right(3),
+		] })),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn merge_replace_right_first() {
+	let merged = merge_timestamps(
+		vec![VecDiff::Replace { values: vec![1, 2] }],
+		vec![VecDiff::Replace { values: vec![2, 3] }],
+		Tiebreak::RightFirst,
+	);
+
+	assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![
+			MergedVecItem::Left(1),
+			MergedVecItem::Right(2),
+			MergedVecItem::Left(2),
+			MergedVecItem::Right(3),
+		] })),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn merge_push_left_first() {
+	let merged = merge_timestamps(
+		vec![VecDiff::Replace { values: vec![5] }],
+		vec![VecDiff::Push { value: 5 }],
+		Tiebreak::LeftFirst,
+	);
+
+	let output = assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![MergedVecItem::Left(5)] })),
+		Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Right(5) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![MergedVecItem::Left(5), MergedVecItem::Right(5)]);
+}
+
+#[test]
+fn merge_push_right_first() {
+	let merged = merge_timestamps(
+		vec![VecDiff::Replace { values: vec![5] }],
+		vec![VecDiff::Push { value: 5 }],
+		Tiebreak::RightFirst,
+	);
+
+	let output = assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![MergedVecItem::Left(5)] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: MergedVecItem::Right(5) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![MergedVecItem::Right(5), MergedVecItem::Left(5)]);
+}
+
+#[test]
+fn merge_insert_between_equal_timestamps() {
+	let merged = merge_timestamps(
+		vec![VecDiff::Replace { values: vec![4, 6] }, VecDiff::InsertAt { index: 1, value: 5 }],
+		vec![VecDiff::Replace { values: vec![5] }],
+		Tiebreak::LeftFirst,
+	);
+
+	let output = assert_signal_vec_eq(merged, vec![
+		Poll::Ready(Some(VecDiff::Replace {
+			values: vec![MergedVecItem::Left(4), MergedVecItem::Right(5), MergedVecItem::Left(6)],
+		})),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: MergedVecItem::Left(5) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![
+		MergedVecItem::Left(4),
+		MergedVecItem::Left(5),
+		MergedVecItem::Right(5),
+		MergedVecItem::Left(6),
+	]);
+}
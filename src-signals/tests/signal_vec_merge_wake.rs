@@ -0,0 +1,55 @@
+mod util;
+
+use crate::util::{with_counting_context, ParkedSource};
+use futures::pin_mut;
+use futures_signals::signal_vec::{SignalVec, VecDiff};
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::merge::MergedVecItem;
+use std::cmp::Ordering;
+use std::sync::atomic::Ordering as AtomicOrdering;
+use std::task::Poll;
+
+#[test]
+fn merge_wakes_after_pending_side_becomes_ready() {
+	let (left, left_handle) = ParkedSource::<VecDiff<u32>>::new();
+	let (right, right_handle) = ParkedSource::<VecDiff<u32>>::new();
+
+	let merged = left.merge(right, |left, right| {
+		if left < right {
+			Ordering::Less
+		} else {
+			Ordering::Greater
+		}
+	});
+
+	pin_mut!(merged);
+
+	with_counting_context(|cx, wakes| {
+		assert_eq!(merged.as_mut().poll_vec_change(cx), Poll::Pending);
+
+		// left becomes ready while right stays parked
+		left_handle.send(VecDiff::Push { value: 1 });
+		assert_eq!(wakes.load(AtomicOrdering::SeqCst), 1);
+
+		assert_eq!(
+			merged.as_mut().poll_vec_change(cx),
+			Poll::Ready(Some(VecDiff::Push { value: MergedVecItem::Left(1) })),
+		);
+
+		assert_eq!(merged.as_mut().poll_vec_change(cx), Poll::Pending);
+
+		// right must have registered a waker during the last poll
+		right_handle.send(VecDiff::Push { value: 2 });
+		assert_eq!(wakes.load(AtomicOrdering::SeqCst), 2);
+		assert!(matches!(merged.as_mut().poll_vec_change(cx), Poll::Ready(Some(_))));
+
+		// and left must have registered one while right was being emitted
+		left_handle.finish();
+		assert_eq!(wakes.load(AtomicOrdering::SeqCst), 3);
+		assert_eq!(merged.as_mut().poll_vec_change(cx), Poll::Pending);
+
+		right_handle.finish();
+		assert_eq!(wakes.load(AtomicOrdering::SeqCst), 4);
+		assert_eq!(merged.as_mut().poll_vec_change(cx), Poll::Ready(None));
+	});
+}
@@ -0,0 +1,55 @@
+mod util;
+
+use crate::util::{assert_signal_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Channel {
+	character_count: u32,
+}
+
+fn channel(character_count: u32) -> Channel {
+	Channel { character_count }
+}
+
+#[test]
+fn scan_sum_character_count() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![channel(40), channel(12)] }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Push { value: channel(8) }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: channel(45) }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::RemoveAt { index: 1 }),
+	]);
+
+	let total = source.scan(0, |total, diff, channels| match diff {
+		VecDiff::Replace { values } => *total = values.iter().map(|channel| channel.character_count).sum(),
+		VecDiff::Push { value } => *total += value.character_count,
+		VecDiff::UpdateAt { index, value } => *total = *total - channels[*index].character_count + value.character_count,
+		VecDiff::RemoveAt { index } => *total -= channels[*index].character_count,
+		_ => unimplemented!(),
+	});
+
+	assert_signal_eq(total, vec![
+		Poll::Ready(Some(52)),
+		Poll::Ready(Some(60)),
+		Poll::Ready(Some(65)),
+		Poll::Ready(Some(53)),
+		Poll::Ready(None),
+	]);
+}
+
+#[test]
+fn scan_emits_initial_state() {
+	let source = Source::<VecDiff<Channel>>::new(vec![]);
+	let count = source.scan(0usize, |count, _, _| *count += 1);
+
+	assert_signal_eq(count, vec![
+		Poll::Ready(Some(0)),
+		Poll::Ready(None),
+	]);
+}
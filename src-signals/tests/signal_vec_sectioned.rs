@@ -0,0 +1,89 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::sectioned::SectionedItem;
+use std::task::Poll;
+
+#[derive(Debug, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+enum Status {
+	Looking,
+	Online,
+	Away,
+}
+
+type Member = (&'static str, Status);
+
+fn header(status: Status) -> SectionedItem<Status, Member> {
+	SectionedItem::Header(status)
+}
+
+fn member(name: &'static str, status: Status) -> SectionedItem<Status, Member> {
+	SectionedItem::Item((name, status))
+}
+
+#[test]
+fn sectioned_status_change_moves_member() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![
+			("alice", Status::Online),
+			("bob", Status::Looking),
+			("carol", Status::Online),
+		] }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: ("alice", Status::Away) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: ("carol", Status::Away) }),
+	]);
+
+	let output = assert_signal_vec_eq(source.sectioned(|(_, status)| *status), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![
+			header(Status::Looking),
+			member("bob", Status::Looking),
+			header(Status::Online),
+			member("alice", Status::Online),
+			member("carol", Status::Online),
+		] })),
+
+		// alice moves from Online to a new Away section
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 3 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 4, value: header(Status::Away) })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 5, value: member("alice", Status::Away) })),
+
+		// carol leaves Online empty, removing its header
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 3 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 2 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 4, value: member("carol", Status::Away) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![
+		header(Status::Looking),
+		member("bob", Status::Looking),
+		header(Status::Away),
+		member("alice", Status::Away),
+		member("carol", Status::Away),
+	]);
+}
+
+#[test]
+fn sectioned_push_and_pop() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: ("alice", Status::Away) }),
+		Poll::Ready(VecDiff::Push { value: ("bob", Status::Looking) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: ("bobby", Status::Looking) }),
+		Poll::Ready(VecDiff::Pop {}),
+	]);
+
+	let output = assert_signal_vec_eq(source.sectioned(|(_, status)| *status), vec![
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: header(Status::Away) })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: member("alice", Status::Away) })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: header(Status::Looking) })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: member("bob", Status::Looking) })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: member("bobby", Status::Looking) })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![header(Status::Away), member("alice", Status::Away)]);
+}
@@ -0,0 +1,80 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::sorted::reverse;
+use std::task::Poll;
+
+// characters and the number of messages they have sent
+type Item = (char, u32);
+
+#[test]
+fn sorted_update_moves_item() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![('a', 3), ('b', 1), ('c', 2)] }),
+		Poll::Ready(VecDiff::UpdateAt { index: 1, value: ('b', 5) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: ('a', 4) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 2, value: ('c', 0) }),
+	]);
+
+	let sorted = source.sorted_by_key(|item: &Item| item.1);
+
+	let output = assert_signal_vec_eq(sorted, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('b', 1), ('c', 2), ('a', 3)] })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 0, value: ('b', 5) })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 1, value: ('a', 4) })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 0, value: ('c', 0) })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('c', 0), ('a', 4), ('b', 5)]);
+}
+
+#[test]
+fn sorted_reverse_newest_first() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![('a', 1), ('b', 2)] }),
+		Poll::Ready(VecDiff::Push { value: ('c', 3) }),
+		Poll::Ready(VecDiff::Push { value: ('d', 0) }),
+		Poll::Ready(VecDiff::UpdateAt { index: 3, value: ('d', 4) }),
+	]);
+
+	let sorted = source.sorted_by(reverse(|left: &Item, right: &Item| left.1.cmp(&right.1)));
+
+	let output = assert_signal_vec_eq(sorted, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('b', 2), ('a', 1)] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: ('c', 3) })),
+		Poll::Ready(Some(VecDiff::Push { value: ('d', 0) })),
+		Poll::Ready(Some(VecDiff::UpdateAt { index: 3, value: ('d', 4) })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 3, new_index: 0 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('d', 4), ('c', 3), ('b', 2), ('a', 1)]);
+}
+
+#[test]
+fn sorted_equal_keys_keep_source_order() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 2)] }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: ('d', 1) }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 2 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 3 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 1 }),
+	]);
+
+	let sorted = source.sorted_by_key(|item: &Item| item.1);
+
+	let output = assert_signal_vec_eq(sorted, vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![('a', 1), ('b', 1), ('c', 2)] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: ('d', 1) })),
+		Poll::Ready(Some(VecDiff::Move { old_index: 0, new_index: 2 })),
+		Poll::Ready(Some(VecDiff::Pop {})),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![('a', 1), ('d', 1)]);
+}
@@ -0,0 +1,110 @@
+mod util;
+
+use crate::util::{assert_signal_vec_eq, Source};
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use std::task::Poll;
+
+#[test]
+fn take_straddling_boundary() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3, 4] }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 0 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 1 }),
+		Poll::Ready(VecDiff::InsertAt { index: 3, value: 9 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 4 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.take(3), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2, 3] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 2 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: 0 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::Push { value: 3 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![0, 2, 3]);
+}
+
+#[test]
+fn take_move_across_boundary() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3] }),
+		Poll::Ready(VecDiff::Move { old_index: 0, new_index: 2 }),
+		Poll::Ready(VecDiff::Move { old_index: 2, new_index: 0 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.take(2), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![1, 2] })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(Some(VecDiff::Push { value: 3 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 1 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: 1 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1, 2]);
+}
+
+#[test]
+fn take_below_capacity() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: 1 }),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Push { value: 3 }),
+		Poll::Ready(VecDiff::Pop {}),
+		Poll::Ready(VecDiff::Pop {}),
+	]);
+
+	let output = assert_signal_vec_eq(source.take(2), vec![
+		Poll::Ready(Some(VecDiff::Push { value: 1 })),
+		Poll::Ready(Some(VecDiff::Push { value: 2 })),
+		Poll::Ready(Some(VecDiff::Pop {})),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![1]);
+}
+
+#[test]
+fn skip_straddling_boundary() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec![1, 2, 3, 4] }),
+		Poll::Ready(VecDiff::InsertAt { index: 0, value: 0 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 1 }),
+		Poll::Ready(VecDiff::InsertAt { index: 3, value: 9 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+		Poll::Ready(VecDiff::Push { value: 5 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.skip(2), vec![
+		Poll::Ready(Some(VecDiff::Replace { values: vec![3, 4] })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 0, value: 2 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(Some(VecDiff::InsertAt { index: 1, value: 9 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(Some(VecDiff::Push { value: 5 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, vec![9, 4, 5]);
+}
+
+#[test]
+fn skip_below_count() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Push { value: 1 }),
+		Poll::Ready(VecDiff::Push { value: 2 }),
+		Poll::Ready(VecDiff::Push { value: 3 }),
+		Poll::Ready(VecDiff::RemoveAt { index: 0 }),
+	]);
+
+	let output = assert_signal_vec_eq(source.skip(2), vec![
+		Poll::Ready(Some(VecDiff::Push { value: 3 })),
+		Poll::Ready(Some(VecDiff::RemoveAt { index: 0 })),
+		Poll::Ready(None),
+	]);
+
+	assert_eq!(output, Vec::<u32>::new());
+}
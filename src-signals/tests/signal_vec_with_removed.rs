@@ -0,0 +1,41 @@
+mod util;
+
+use crate::util::Source;
+use futures::executor::block_on_stream;
+use futures_signals::signal_vec::VecDiff;
+use snowcat_signals::signal_vec::SnowcatSignalVecExt;
+use snowcat_signals::signal_vec::with_removed::VecDiffWithRemoved;
+use std::task::Poll;
+
+#[test]
+fn with_removed_reports_removed_items() {
+	let source = Source::new(vec![
+		Poll::Ready(VecDiff::Replace { values: vec!["a", "b", "c", "d"] }),
+		Poll::Ready(VecDiff::RemoveAt { index: 1 }),
+		Poll::Pending,
+		Poll::Ready(VecDiff::Pop {}),
+		Poll::Ready(VecDiff::Move { old_index: 1, new_index: 0 }),
+		Poll::Ready(VecDiff::UpdateAt { index: 0, value: "e" }),
+		Poll::Ready(VecDiff::Clear {}),
+	]);
+
+	let changes: Vec<_> = block_on_stream(Box::pin(source.with_removed())).collect();
+
+	assert_eq!(changes, vec![
+		VecDiffWithRemoved::Replace { values: vec!["a", "b", "c", "d"], removed: vec![] },
+		VecDiffWithRemoved::RemoveAt { index: 1, removed: "b" },
+		VecDiffWithRemoved::Pop { index: 2, removed: "d" },
+		VecDiffWithRemoved::Move { old_index: 1, new_index: 0, value: "c" },
+		VecDiffWithRemoved::UpdateAt { index: 0, value: "e", removed: "c" },
+		VecDiffWithRemoved::Clear { removed: vec!["e", "a"] },
+	]);
+}
+
+#[test]
+fn with_removed_into_diff() {
+	let removed = VecDiffWithRemoved::RemoveAt { index: 3, removed: 'x' };
+	let popped = VecDiffWithRemoved::Pop { index: 0, removed: 'y' };
+
+	assert_eq!(removed.into_diff(), VecDiff::RemoveAt { index: 3 });
+	assert_eq!(popped.into_diff(), VecDiff::Pop {});
+}
@@ -10,8 +10,9 @@ use std::fmt::Debug;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::pin::Pin;
-use std::sync::Arc;
-use std::task::{Context, Poll};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::task::{Context, Poll, Waker};
 use std::thread;
 use std::time::Duration;
 
@@ -19,6 +20,7 @@ use std::time::Duration;
 // SIGNALS
 // -----------------------------------------------------------------------------
 
+#[derive(Debug)]
 pub struct Source<T> {
 	changes: VecDeque<Poll<T>>,
 }
@@ -83,6 +85,97 @@ where K: Debug,
 	}
 }
 
+/// A source which, unlike [`Source`], does not wake itself when it has no
+/// changes ready. Instead, it parks the waker it was last polled with until
+/// a change is sent through its [`ParkedSourceHandle`].
+#[derive(Debug)]
+pub struct ParkedSource<T> {
+	state: Arc<Mutex<ParkedState<T>>>,
+}
+
+#[derive(Debug)]
+pub struct ParkedSourceHandle<T> {
+	state: Arc<Mutex<ParkedState<T>>>,
+}
+
+#[derive(Debug)]
+struct ParkedState<T> {
+	changes: VecDeque<T>,
+	is_done: bool,
+	waker: Option<Waker>,
+}
+
+#[allow(dead_code)]
+impl<T> ParkedSource<T>
+where T: Debug {
+	pub fn new() -> (Self, ParkedSourceHandle<T>) {
+		let state = Arc::new(Mutex::new(ParkedState {
+			changes: VecDeque::new(),
+			is_done: false,
+			waker: None,
+		}));
+
+		(ParkedSource { state: Arc::clone(&state) }, ParkedSourceHandle { state })
+	}
+
+	fn poll(&mut self, cx: &mut Context) -> Poll<Option<T>> {
+		let mut state = self.state.lock().expect("parked source state");
+
+		let poll_result = match state.changes.pop_front() {
+			Some(change) => Poll::Ready(Some(change)),
+			None if state.is_done => Poll::Ready(None),
+			None => {
+				state.waker = Some(cx.waker().clone());
+				Poll::Pending
+			},
+		};
+
+		log::trace!("[{module}::ParkedSource<T>::poll] returning {poll_result:?}", module = module_path!());
+		poll_result
+	}
+}
+
+#[allow(dead_code)]
+impl<T> ParkedSourceHandle<T> {
+	pub fn send(&self, change: T) {
+		let mut state = self.state.lock().expect("parked source state");
+		state.changes.push_back(change);
+
+		if let Some(waker) = state.waker.take() {
+			waker.wake();
+		}
+	}
+
+	pub fn finish(&self) {
+		let mut state = self.state.lock().expect("parked source state");
+		state.is_done = true;
+
+		if let Some(waker) = state.waker.take() {
+			waker.wake();
+		}
+	}
+}
+
+impl<T> Signal for ParkedSource<T>
+where T: Debug,
+{
+	type Item = T;
+
+	fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+		self.poll(cx)
+	}
+}
+
+impl<T> SignalVec for ParkedSource<VecDiff<T>>
+where T: Debug,
+{
+	type Item = T;
+
+	fn poll_vec_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<VecDiff<Self::Item>>> {
+		self.poll(cx)
+	}
+}
+
 #[allow(dead_code)]
 pub fn delay() {
 	thread::sleep(Duration::from_millis(50));
@@ -309,6 +402,24 @@ where F: FnOnce(&mut Context) -> U {
 	f(&mut context)
 }
 
+#[allow(dead_code)]
+pub fn with_counting_context<U, F>(f: F) -> U
+where F: FnOnce(&mut Context, &AtomicUsize) -> U {
+	struct Counter(AtomicUsize);
+
+	impl ArcWake for Counter {
+		fn wake_by_ref(counter: &Arc<Self>) {
+			counter.0.fetch_add(1, Ordering::SeqCst);
+		}
+	}
+
+	let counter = Arc::new(Counter(AtomicUsize::new(0)));
+	let waker = waker(Arc::clone(&counter));
+	let mut context = Context::from_waker(&waker);
+
+	f(&mut context, &counter.0)
+}
+
 // -----------------------------------------------------------------------------
 // INTERNAL HELPERS
 // -----------------------------------------------------------------------------
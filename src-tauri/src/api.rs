@@ -1,9 +1,10 @@
+pub mod channels;
 pub mod error;
 pub mod url_helpers;
 
-mod remote;
+pub(crate) mod remote;
 
-use crate::api::characters::CharacterId;
+use crate::api::characters::{CharacterId, CharacterProfile};
 use crate::api::error::Result as ApiResult;
 use crate::api::remote::data::ticket::GetApiTicket;
 use crate::util::hex::{Hex, HexFromStrError};
@@ -18,7 +19,9 @@ use time::{Duration, OffsetDateTime};
 type BookmarksList = Vec<String>;
 type CharactersList = HashMap<CharacterId, String>;
 type FriendsList = HashMap<CharacterId, Vec<String>>;
+type ProfileList = HashMap<String, (OffsetDateTime, CharacterProfile)>;
 
+const PROFILE_LIFETIME: Duration = Duration::minutes(15);
 const TICKET_LIFETIME: Duration = Duration::minutes(5);
 
 #[derive(Serialize)]
@@ -134,6 +137,10 @@ pub struct AccountInfo {
 pub struct ApiClient {
 	account: Mutex<Account>,
 	http: HttpClient,
+
+	/// Character profiles fetched through this client, along with the time at
+	/// which they should be fetched again.
+	profiles: Mutex<ProfileList>,
 }
 
 impl ApiClient {
@@ -169,6 +176,8 @@ impl ApiClient {
 		let client = ApiClient {
 			account,
 			http,
+
+			profiles: Mutex::new(HashMap::new()),
 		};
 
 		let account_info = AccountInfo {
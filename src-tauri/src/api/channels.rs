@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct ChannelInfo {
+	/// The channel's ID. For official channels, this is the same as the
+	/// channel's title.
+	pub id: String,
+	pub title: String,
+	pub character_count: u64,
+	pub is_official: bool,
+}
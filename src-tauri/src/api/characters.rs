@@ -6,6 +6,7 @@ use serde_with::{DeserializeFromStr, SerializeDisplay};
 use thiserror::Error;
 use tracing::{instrument, warn};
 use std::{cmp, str, fmt};
+use std::collections::BTreeMap;
 use std::convert::Infallible;
 use std::future::Future;
 use std::pin::Pin;
@@ -121,12 +122,32 @@ pub enum CharacterIdentifier<'info> {
 	Name(&'info str)
 }
 
-#[derive(Debug, Clone)]
+/// The profile of a character, as shown on their F-List character page.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CharacterProfile {
+	pub name: String,
+	pub custom_title: String,
+	pub description: String,
+
+	/// The IDs of the kinks listed on the profile.
+	pub kinks: Vec<u64>,
+
+	/// The profile's info fields, such as age or species, keyed by field ID.
+	pub custom_fields: BTreeMap<u64, String>,
 
+	pub images: Vec<CharacterProfileImage>,
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, DeserializeFromStr, SerializeDisplay)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharacterProfileImage {
+	pub id: u64,
+	pub extension: String,
+	pub width: u32,
+	pub height: u32,
+	pub description: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd, DeserializeFromStr, SerializeDisplay)]
 pub enum CharacterGender {
 	// Binary genders
 	Female,
@@ -143,6 +164,47 @@ pub enum CharacterGender {
 	#[default] None,
 }
 
+impl CharacterGender {
+	/// Returns the name of the theme variable used to colour characters of
+	/// this gender.
+	pub fn theme_var(&self) -> &'static str {
+		match self {
+			// Binary genders
+			Self::Female => "--sex-female",
+			Self::Male => "--sex-male",
+
+			// Intersex genders
+			Self::CuntBoy => "--sex-cunt-boy",
+			Self::Hermaphrodite => "--sex-herm",
+			Self::MaleHerm => "--sex-male-herm",
+			Self::Shemale => "--sex-shemale",
+
+			// Other genders
+			Self::Transgender => "--sex-transgender",
+			Self::None => "--sex-none",
+		}
+	}
+
+	/// Returns the glyph displayed next to characters of this gender.
+	pub fn glyph(&self) -> &'static str {
+		match self {
+			// Binary genders
+			Self::Female => "\u{2640}",
+			Self::Male => "\u{2642}",
+
+			// Intersex genders
+			Self::CuntBoy => "\u{26A8}",
+			Self::Hermaphrodite => "\u{26A5}",
+			Self::MaleHerm => "\u{26A6}",
+			Self::Shemale => "\u{26A2}",
+
+			// Other genders
+			Self::Transgender => "\u{26A7}",
+			Self::None => "\u{26AA}",
+		}
+	}
+}
+
 impl fmt::Display for CharacterGender {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		use constants::character_info::gender::*;
@@ -461,6 +523,61 @@ impl CharacterStatus {
 			kind: status,
 		}
 	}
+
+	/// Validates a user-initiated change from this status to a new status.
+	///
+	/// The message is trimmed, and an empty message is treated as no message
+	/// at all. Crown can only be assigned by the server, Offline is only
+	/// reached by disconnecting, messages may not be longer than the limit
+	/// enforced by the F-Chat server, and the new status has to differ from
+	/// this one.
+	///
+	/// # Examples
+	/// ```
+	/// use snowcat::api::characters::{CharacterStatus, CharacterStatusKind, StatusError};
+	///
+	/// let status = CharacterStatus::new(CharacterStatusKind::Online);
+	///
+	/// let busy = status.transition_to(CharacterStatusKind::DoNotDisturb, Some("  writing  "));
+	/// assert_eq!(busy, Ok(CharacterStatus::new_with_message("writing", CharacterStatusKind::DoNotDisturb)));
+	///
+	/// let offline = status.transition_to(CharacterStatusKind::Offline, Some("bye"));
+	/// assert_eq!(offline, Err(StatusError::KindNotSelectable(CharacterStatusKind::Offline)));
+	/// ```
+	pub fn transition_to(&self, kind: CharacterStatusKind, message: Option<&str>) -> Result<CharacterStatus, StatusError> {
+		use constants::character_info::status::STATUS_MESSAGE_MAX_LENGTH;
+
+		if matches!(kind, CharacterStatusKind::Crown | CharacterStatusKind::Offline) {
+			return Err(StatusError::KindNotSelectable(kind));
+		}
+
+		let message = message.map(str::trim).filter(|message| !message.is_empty());
+
+		if kind == self.kind && message == self.message.as_deref() {
+			return Err(StatusError::Unchanged);
+		}
+
+		match message {
+			Some(message) if message.chars().count() > STATUS_MESSAGE_MAX_LENGTH => {
+				Err(StatusError::MessageTooLong(message.chars().count(), STATUS_MESSAGE_MAX_LENGTH))
+			},
+
+			Some(message) => Ok(CharacterStatus::new_with_message(message, kind)),
+			None => Ok(CharacterStatus::new(kind)),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub enum StatusError {
+	#[error("The status {0:?} cannot be picked by the user.")]
+	KindNotSelectable(CharacterStatusKind),
+
+	#[error("The status message is {0} characters long, but may be at most {1} characters long.")]
+	MessageTooLong(usize, usize),
+
+	#[error("The status is already set to this kind and message.")]
+	Unchanged,
 }
 
 impl str::FromStr for CharacterStatus {
@@ -494,7 +611,11 @@ impl str::FromStr for CharacterStatus {
 	}
 }
 
-#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Deserialize, Serialize)]
+/// Statuses are ordered by their [`display_priority`], so that anything keyed
+/// by status iterates in display order.
+///
+/// [`display_priority`]: CharacterStatusKind::display_priority
+#[derive(Debug, Clone, Copy, Default, Eq, Hash, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum CharacterStatusKind {
 	#[default] Online,
@@ -509,3 +630,211 @@ pub enum CharacterStatusKind {
 
 	Offline,
 }
+
+impl CharacterStatusKind {
+	/// Returns the name of the theme variable used to colour this status.
+	pub fn theme_var(&self) -> &'static str {
+		match self {
+			Self::Online => "--status-online",
+			Self::Crown => "--status-crown",
+			Self::Looking => "--status-looking",
+			Self::Idle => "--status-idle",
+			Self::Away => "--status-away",
+			Self::Busy => "--status-busy",
+			Self::DoNotDisturb => "--status-dnd",
+			Self::Offline => "--status-offline",
+		}
+	}
+
+	/// Returns the glyph displayed next to characters with this status.
+	pub fn glyph(&self) -> &'static str {
+		match self {
+			Self::Online => "\u{25CF}",
+			Self::Crown => "\u{2605}",
+			Self::Looking => "\u{25C9}",
+			Self::Idle => "\u{25D0}",
+			Self::Away => "\u{25CB}",
+			Self::Busy => "\u{25A0}",
+			Self::DoNotDisturb => "\u{2298}",
+			Self::Offline => "\u{25CC}",
+		}
+	}
+
+	/// Returns `true` if characters with this status are open to being
+	/// approached.
+	pub fn is_available(&self) -> bool {
+		matches!(self, Self::Looking | Self::Online)
+	}
+
+	/// Returns where characters with this status are placed when sorting a
+	/// roster for display, lowest first. Available characters come before
+	/// everyone else, and offline characters come last.
+	pub fn display_priority(&self) -> u8 {
+		match self {
+			Self::Looking => 0,
+			Self::Online => 1,
+			Self::Crown => 2,
+			Self::Idle => 3,
+			Self::Away => 4,
+			Self::Busy => 5,
+			Self::DoNotDisturb => 6,
+			Self::Offline => 7,
+		}
+	}
+}
+
+impl PartialOrd for CharacterStatusKind {
+	fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for CharacterStatusKind {
+	fn cmp(&self, other: &Self) -> cmp::Ordering {
+		self.display_priority().cmp(&other.display_priority())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CharacterGender, CharacterStatus, CharacterStatusKind, StatusError};
+
+	#[test]
+	fn gender_theme_vars() {
+		let expected = [
+			(CharacterGender::Female, "--sex-female"),
+			(CharacterGender::Male, "--sex-male"),
+			(CharacterGender::CuntBoy, "--sex-cunt-boy"),
+			(CharacterGender::Hermaphrodite, "--sex-herm"),
+			(CharacterGender::MaleHerm, "--sex-male-herm"),
+			(CharacterGender::Shemale, "--sex-shemale"),
+			(CharacterGender::Transgender, "--sex-transgender"),
+			(CharacterGender::None, "--sex-none"),
+		];
+
+		for (gender, var) in expected {
+			assert_eq!(gender.theme_var(), var, "wrong theme variable for {gender:?}");
+		}
+	}
+
+	#[test]
+	fn status_theme_vars() {
+		let expected = [
+			(CharacterStatusKind::Online, "--status-online"),
+			(CharacterStatusKind::Crown, "--status-crown"),
+			(CharacterStatusKind::Looking, "--status-looking"),
+			(CharacterStatusKind::Idle, "--status-idle"),
+			(CharacterStatusKind::Away, "--status-away"),
+			(CharacterStatusKind::Busy, "--status-busy"),
+			(CharacterStatusKind::DoNotDisturb, "--status-dnd"),
+			(CharacterStatusKind::Offline, "--status-offline"),
+		];
+
+		for (status, var) in expected {
+			assert_eq!(status.theme_var(), var, "wrong theme variable for {status:?}");
+		}
+	}
+
+	const ALL_STATUSES: [CharacterStatusKind; 8] = [
+		CharacterStatusKind::Online,
+		CharacterStatusKind::Crown,
+		CharacterStatusKind::Looking,
+		CharacterStatusKind::Idle,
+		CharacterStatusKind::Away,
+		CharacterStatusKind::Busy,
+		CharacterStatusKind::DoNotDisturb,
+		CharacterStatusKind::Offline,
+	];
+
+	#[test]
+	fn status_availability() {
+		for status in ALL_STATUSES {
+			let expected = matches!(status, CharacterStatusKind::Looking | CharacterStatusKind::Online);
+			assert_eq!(status.is_available(), expected, "wrong availability for {status:?}");
+		}
+	}
+
+	#[test]
+	fn status_display_priority() {
+		let mut statuses = ALL_STATUSES;
+		statuses.sort_by_key(CharacterStatusKind::display_priority);
+
+		assert_eq!(statuses, [
+			CharacterStatusKind::Looking,
+			CharacterStatusKind::Online,
+			CharacterStatusKind::Crown,
+			CharacterStatusKind::Idle,
+			CharacterStatusKind::Away,
+			CharacterStatusKind::Busy,
+			CharacterStatusKind::DoNotDisturb,
+			CharacterStatusKind::Offline,
+		]);
+
+		// available characters always come first
+		let first_unavailable = statuses.iter().position(|status| !status.is_available()).unwrap();
+		assert!(statuses[first_unavailable..].iter().all(|status| !status.is_available()));
+	}
+
+	#[test]
+	fn status_order_matches_display_priority() {
+		let mut by_priority = ALL_STATUSES;
+		by_priority.sort_by_key(CharacterStatusKind::display_priority);
+
+		let mut sorted = ALL_STATUSES;
+		sorted.sort();
+
+		assert_eq!(sorted, by_priority);
+	}
+
+	#[test]
+	fn status_transition_offline_with_message() {
+		let status = CharacterStatus::new(CharacterStatusKind::Online);
+		let result = status.transition_to(CharacterStatusKind::Offline, Some("gone for the night"));
+
+		assert_eq!(result, Err(StatusError::KindNotSelectable(CharacterStatusKind::Offline)));
+	}
+
+	#[test]
+	fn status_transition_dnd_with_message() {
+		let status = CharacterStatus::new_with_message("around", CharacterStatusKind::Looking);
+		let result = status.transition_to(CharacterStatusKind::DoNotDisturb, Some(" in a scene "));
+
+		assert_eq!(result, Ok(CharacterStatus::new_with_message("in a scene", CharacterStatusKind::DoNotDisturb)));
+	}
+
+	#[test]
+	fn status_transition_empty_message() {
+		let status = CharacterStatus::new(CharacterStatusKind::Online);
+		let result = status.transition_to(CharacterStatusKind::Away, Some("   "));
+
+		assert_eq!(result, Ok(CharacterStatus::new(CharacterStatusKind::Away)));
+	}
+
+	#[test]
+	fn status_transition_unchanged() {
+		let status = CharacterStatus::new_with_message("around", CharacterStatusKind::Looking);
+
+		assert_eq!(status.transition_to(CharacterStatusKind::Looking, Some(" around ")), Err(StatusError::Unchanged));
+		assert_eq!(
+			status.transition_to(CharacterStatusKind::Looking, None),
+			Ok(CharacterStatus::new(CharacterStatusKind::Looking)),
+		);
+	}
+
+	#[test]
+	fn status_transition_message_too_long() {
+		let status = CharacterStatus::new(CharacterStatusKind::Online);
+		let message = "a".repeat(256);
+		let result = status.transition_to(CharacterStatusKind::Away, Some(&message));
+
+		assert_eq!(result, Err(StatusError::MessageTooLong(256, 255)));
+	}
+
+	#[test]
+	fn status_transition_to_crown() {
+		let status = CharacterStatus::new(CharacterStatusKind::Online);
+		let result = status.transition_to(CharacterStatusKind::Crown, None);
+
+		assert_eq!(result, Err(StatusError::KindNotSelectable(CharacterStatusKind::Crown)));
+	}
+}
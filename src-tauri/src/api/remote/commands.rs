@@ -29,6 +29,13 @@ pub enum CharacterTypingStatus {
 
 #[derive(Debug, SerializeDisplay)]
 pub struct ClientVersion(u8, u8, u16);
+
+impl ClientVersion {
+	pub const fn new(major: u8, minor: u8, patch: u16) -> Self {
+		ClientVersion(major, minor, patch)
+	}
+}
+
 impl fmt::Display for ClientVersion {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "{}.{}.{}", self.0, self.1, self.2)
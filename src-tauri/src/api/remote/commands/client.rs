@@ -1,4 +1,4 @@
-use crate::api::remote::characters;
+use crate::api::characters;
 use crate::api::remote::commands::client::helpers::command_prefix;
 use serde::Serialize;
 use serde::ser::SerializeStruct;
@@ -194,6 +194,7 @@ command_prefix!(UserSearch, "FKS");
 #[derive(Debug, Serialize)]
 pub struct UserIdentify {
 	account: String,
+	character: String,
 
 	#[serde(rename = "cname")]
 	client_name: String,
@@ -208,6 +209,27 @@ pub struct UserIdentify {
 
 command_prefix!(UserIdentify, "IDN");
 
+impl UserIdentify {
+	pub fn with_ticket(
+		account: &str,
+		ticket: &str,
+		character: &str,
+		client_name: &str,
+		client_version: super::ClientVersion,
+	) -> Self {
+		UserIdentify {
+			account: account.to_owned(),
+			character: character.to_owned(),
+			client_name: client_name.to_owned(),
+			client_version,
+
+			method: data::UserIdentificationData::Ticket {
+				ticket: ticket.to_owned(),
+			},
+		}
+	}
+}
+
 #[derive(Debug, Serialize)]
 #[serde(tag = "action")]
 pub enum UserIgnoreListAction {
@@ -240,6 +262,14 @@ pub struct ChannelJoin {
 
 command_prefix!(ChannelJoin, "JCH");
 
+impl ChannelJoin {
+	pub fn new(channel_id: &str) -> Self {
+		ChannelJoin {
+			channel_id: channel_id.to_owned(),
+		}
+	}
+}
+
 #[derive(Debug, Serialize)]
 pub struct ChannelDelete {
 	#[serde(rename = "channel")]
@@ -270,6 +300,14 @@ pub struct ChannelLeave {
 
 command_prefix!(ChannelLeave, "LCH");
 
+impl ChannelLeave {
+	pub fn new(channel_id: &str) -> Self {
+		ChannelLeave {
+			channel_id: channel_id.to_owned(),
+		}
+	}
+}
+
 #[derive(Debug, Serialize)]
 pub struct ChannelSendAd {
 	#[serde(rename = "channel")]
@@ -290,6 +328,15 @@ pub struct ChannelSendMessage {
 
 command_prefix!(ChannelSendMessage, "MSG");
 
+impl ChannelSendMessage {
+	pub fn new(channel_id: &str, message: &str) -> Self {
+		ChannelSendMessage {
+			channel_id: channel_id.to_owned(),
+			message: message.to_owned(),
+		}
+	}
+}
+
 #[derive(Debug)]
 pub struct ServerListOpenChannels;
 
@@ -310,6 +357,15 @@ pub struct CharacterSendMessage {
 
 command_prefix!(CharacterSendMessage, "PRI");
 
+impl CharacterSendMessage {
+	pub fn new(character: &str, message: &str) -> Self {
+		CharacterSendMessage {
+			character: character.to_owned(),
+			message: message.to_owned(),
+		}
+	}
+}
+
 #[derive(Debug, Serialize)]
 pub struct CharacterGetProfileData {
 	character: String,
@@ -404,6 +460,23 @@ pub struct UserChangeStatus {
 
 command_prefix!(UserChangeStatus, "STA");
 
+impl UserChangeStatus {
+	/// Change from the `current` status, validated by
+	/// [`CharacterStatus::transition_to`](characters::CharacterStatus::transition_to).
+	pub fn new(
+		current: &characters::CharacterStatus,
+		status: characters::CharacterStatusKind,
+		message: Option<&str>,
+	) -> Result<Self, characters::StatusError> {
+		let characters::CharacterStatus { kind, message } = current.transition_to(status, message)?;
+
+		Ok(UserChangeStatus {
+			status: kind,
+			message,
+		})
+	}
+}
+
 impl Serialize for UserChangeStatus {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1,8 +1,9 @@
-use crate::api::remote::characters;
+use crate::api::characters;
 use crate::api::remote::commands::server::helpers::command_prefix;
 use serde::Deserialize;
 use serde_json::Value;
 use serde_with::{serde_as, DefaultOnError, DisplayFromStr};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::marker::PhantomData;
 
@@ -405,25 +406,57 @@ pub struct ReceiveAd<'data> {
 
 command_prefix!(ReceiveAd<'_>, "LRP");
 
+// messages are free text, so they are likely to contain escapes that cannot
+// be borrowed from the wire string.
 #[derive(Debug, Deserialize)]
 pub struct ReceiveMessage<'data> {
-	#[serde(rename = "channel")]
-	channel_id: &'data str,
+	#[serde(borrow, rename = "channel")]
+	channel_id: Cow<'data, str>,
 
-	character: &'data str,
-	message: &'data str,
+	#[serde(borrow)]
+	character: Cow<'data, str>,
+
+	#[serde(borrow)]
+	message: Cow<'data, str>,
 }
 
 command_prefix!(ReceiveMessage<'_>, "MSG");
 
+impl ReceiveMessage<'_> {
+	pub fn channel_id(&self) -> &str {
+		&self.channel_id
+	}
+
+	pub fn character(&self) -> &str {
+		&self.character
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ReceivePrivateMessage<'data> {
-	character: &'data str,
-	message: &'data str,
+	#[serde(borrow)]
+	character: Cow<'data, str>,
+
+	#[serde(borrow)]
+	message: Cow<'data, str>,
 }
 
 command_prefix!(ReceivePrivateMessage<'_>, "PRI");
 
+impl ReceivePrivateMessage<'_> {
+	pub fn character(&self) -> &str {
+		&self.character
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
 // SERVER MODERATION
 
 #[derive(Debug, Deserialize)]
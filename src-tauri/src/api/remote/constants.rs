@@ -229,6 +229,8 @@ pub mod character_info {
 		pub const STATUS_AWAY: &str = "away";
 		pub const STATUS_BUSY: &str = "busy";
 		pub const STATUS_DO_NOT_DISTURB: &str = "dnd";
+
+		pub const STATUS_MESSAGE_MAX_LENGTH: usize = 255;
 	}
 }
 
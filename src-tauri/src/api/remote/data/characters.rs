@@ -1,4 +1,5 @@
-use crate::api::{Account, ApiClient};
+use crate::api::{Account, ApiClient, PROFILE_LIFETIME};
+use crate::api::characters::{CharacterProfile, CharacterProfileImage};
 use crate::api::error::{DeserializeError, Result as ApiResult};
 use crate::api::remote::data::mock;
 use reqwest::Client as HttpClient;
@@ -25,6 +26,29 @@ impl ApiClient {
 			.execute(self.http()).await
 	}
 
+	/// Fetch the profile of a character.
+	///
+	/// Profiles are cached by character name, and are fetched again once they
+	/// are more than 15 minutes old. Expired profiles are dropped from the
+	/// cache whenever a new profile is added to it.
+	pub async fn character_profile(&self, character: &str) -> ApiResult<CharacterProfile> {
+		let now = OffsetDateTime::now_utc();
+
+		if let Some((expires_at, profile)) = self.profiles.lock().await.get(character) {
+			if *expires_at > now {
+				return Ok(profile.clone());
+			}
+		}
+
+		let profile = CharacterProfile::from(self.get_character(character).await?);
+
+		let mut profiles = self.profiles.lock().await;
+		profiles.retain(|_, (expires_at, _)| *expires_at > now);
+		profiles.insert(character.to_owned(), (now + PROFILE_LIFETIME, profile.clone()));
+
+		Ok(profile)
+	}
+
 	pub async fn list_characters(&self) -> ApiResult<Vec<String>> {
 		let mut account = self.account.lock().await;
 		let account = &mut *account;
@@ -142,6 +166,35 @@ pub struct GetChararacterResponse {
 
 	#[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
 	pub inlines: BTreeMap<u64, InlineImage>,
+
+	#[serde(default)]
+	#[serde_as(as = "BTreeMap<DisplayFromStr, _>")]
+	pub infotags: BTreeMap<u64, String>,
+}
+
+impl From<GetChararacterResponse> for CharacterProfile {
+	fn from(response: GetChararacterResponse) -> Self {
+		let mut images = response.images;
+		images.sort_by_key(|image| image.sort_order);
+
+		CharacterProfile {
+			name: response.name,
+			custom_title: response.custom_title,
+			description: response.description,
+			kinks: response.kinks,
+			custom_fields: response.infotags,
+
+			images: images.into_iter()
+				.map(|image| CharacterProfileImage {
+					id: image.id,
+					extension: image.extension,
+					width: image.width,
+					height: image.height,
+					description: image.description,
+				})
+				.collect(),
+		}
+	}
 }
 
 impl Default for GetChararacterResponse {
@@ -257,7 +310,7 @@ fn hours_to_offset<'de, D>(deserializer: D) -> Result<UtcOffset, D::Error>
 where
 	D: serde::Deserializer<'de>,
 {
-	use serde::de::{Error, Visitor};
+	use serde::de::{Error, Unexpected, Visitor};
 
 	struct OffsetVisitor;
 	impl<'de> Visitor<'de> for OffsetVisitor {
@@ -273,6 +326,23 @@ where
 		{
 			UtcOffset::from_hms(v, 0, 0).map_err(|err| Error::custom(err))
 		}
+
+		// JSON numbers are always handed to the visitor as 64-bit integers
+		fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			let hours = i8::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Signed(v), &self))?;
+			self.visit_i8(hours)
+		}
+
+		fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+		where
+			E: Error,
+		{
+			let hours = i8::try_from(v).map_err(|_| Error::invalid_value(Unexpected::Unsigned(v), &self))?;
+			self.visit_i8(hours)
+		}
 	}
 
 	deserializer.deserialize_i8(OffsetVisitor)
@@ -305,3 +375,65 @@ where
 
 	deserializer.deserialize_str(IdVisitor).map(CharacterId::from)
 }
+
+#[cfg(test)]
+mod tests {
+	use super::GetChararacterResponse;
+	use crate::api::characters::CharacterProfile;
+
+	// Recorded from character-data.php, trimmed down to a single image and
+	// inline
+	const RECORDED_RESPONSE: &str = r#"{
+		"id": "123456",
+		"name": "Snow Cat",
+		"description": "[b]Hello![/b] [eicon]snowcat[/eicon]",
+		"custom_title": "Very Cold",
+		"is_self": false,
+		"views": 42,
+		"badges": ["chat-op"],
+		"images": [
+			{"image_id": "2002", "sort_order": 2, "width": "800", "height": "600", "extension": "png", "description": "Second"},
+			{"image_id": "2001", "sort_order": 1, "width": "1024", "height": "768", "extension": "jpg", "description": "First"}
+		],
+		"timezone": -5,
+		"character_list": [{"id": 654321, "name": "Other Cat"}],
+		"settings": {
+			"customs_first": true,
+			"prevent_bookmarks": false,
+			"show_friends": true,
+			"public": true,
+			"guestbook": false
+		},
+		"current_user": {"animated_icons": true, "inline_mode": 1},
+		"created_at": 1600000000,
+		"updated_at": 1650000000,
+		"kinks": ["18", "42"],
+		"inlines": {"7": {"hash": "abcdef", "extension": "png", "nsfw": false}},
+		"infotags": {"1": "25", "3": "Snow Leopard"}
+	}"#;
+
+	#[test]
+	fn deserialize_recorded_profile() {
+		let response: GetChararacterResponse = serde_json::from_str(RECORDED_RESPONSE).unwrap();
+		assert_eq!(response.offset.whole_hours(), -5);
+
+		let profile = CharacterProfile::from(response);
+		assert_eq!(profile.name, "Snow Cat");
+		assert_eq!(profile.custom_title, "Very Cold");
+		assert_eq!(profile.description, "[b]Hello![/b] [eicon]snowcat[/eicon]");
+		assert_eq!(profile.kinks, vec![18, 42]);
+		assert_eq!(profile.custom_fields.get(&3).map(String::as_str), Some("Snow Leopard"));
+
+		let image_ids: Vec<_> = profile.images.iter().map(|image| image.id).collect();
+		assert_eq!(image_ids, vec![2001, 2002]);
+		assert_eq!((profile.images[0].width, profile.images[0].height), (1024, 768));
+	}
+
+	#[test]
+	fn deserialize_profile_without_infotags() {
+		let payload = RECORDED_RESPONSE.replace(r#""infotags""#, r#""unknown""#);
+		let response: GetChararacterResponse = serde_json::from_str(&payload).unwrap();
+
+		assert!(CharacterProfile::from(response).custom_fields.is_empty());
+	}
+}
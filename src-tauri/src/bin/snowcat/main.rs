@@ -6,11 +6,14 @@
 use snowcat::{
 	api,
 	client,
+	session,
 	socket,
 	state,
 	util,
+	window,
 };
 use snowcat::state::tauri as tauri_state;
+use tauri::Manager;
 use tauri::async_runtime::RwLock;
 
 fn main() {
@@ -19,6 +22,31 @@ fn main() {
 	tauri::Builder::default()
 		.manage(channels)
 		.manage(characters)
+		.setup(|app| {
+			let config_dir = app.path_resolver().app_dir()
+				.expect("app config directory should be resolvable");
+
+			let session = session::SessionStore::new(&config_dir);
+			let window_state = window::WindowStateStore::new(&config_dir);
+
+			if let Some(main_window) = app.get_window("main") {
+				if let Err(err) = window_state.restore_window(&main_window) {
+					log::warn!("failed to restore window state: {err}");
+				}
+
+				window_state.track(&main_window);
+			}
+
+			app.manage(session);
+			app.manage(window_state);
+			Ok(())
+		})
+		.invoke_handler(tauri::generate_handler![
+			session::restore_last_active,
+			session::save_last_active,
+			window::restore_window_state,
+			window::save_window_state,
+		])
 		.run(tauri::generate_context!())
 		.expect("error while running tauri application");
 }
@@ -1,26 +1,494 @@
 use thiserror::Error;
 
 use crate::api::ApiClient;
-use crate::api::characters::{CharacterId, CharacterInfo};
+use crate::api::channels::ChannelInfo;
+use crate::api::characters::{CharacterGender, CharacterId, CharacterInfo, CharacterStatus, CharacterStatusKind};
+use crate::io::protocol::ServerCommand;
+use futures_signals::signal::{Mutable, MutableSignalCloned};
+use futures_signals::signal_map::{MutableBTreeMap, MutableSignalMap};
+use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
-use std::collections::BTreeMap;
-use std::ops::{Index, IndexMut};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::{Deref, Index, IndexMut};
+use std::sync::RwLock;
+use std::time::Duration;
 
+pub type ChannelListResult<T> = Result<T, ChannelListError>;
 pub type CharacterListResult<T> = Result<T, CharacterListError>;
 
 pub struct Client {
 	api: Option<ApiClient>,
 	character_cache: CharacterList,
+	connection_status: Mutable<ConnectionStatus>,
+	ignore_list: IgnoreList,
 }
 
-pub struct ChannelList(BTreeMap<String, /*ChannelInfo*/ ()>);
+impl Client {
+	/// Returns `true` if the user is ignoring the given character.
+	pub fn is_ignored(&self, character: &str) -> bool {
+		self.ignore_list.contains(character)
+	}
+
+	/// Drops messages sent by ignored characters, passing every other command
+	/// through.
+	pub fn filter_incoming<'data>(&self, command: ServerCommand<'data>) -> Option<ServerCommand<'data>> {
+		self.ignore_list.allows(&command).then_some(command)
+	}
+
+	/// Returns a signal of the state of the connection to the chat server,
+	/// for the UI to show while the client is reconnecting.
+	pub fn connection_status(&self) -> MutableSignalCloned<ConnectionStatus> {
+		self.connection_status.signal_cloned()
+	}
+
+	/// Record a change in the state of the connection to the chat server,
+	/// notifying the [`connection_status`] signal if it changed.
+	///
+	/// [`connection_status`]: Client::connection_status
+	pub fn set_connection_status(&self, status: ConnectionStatus) {
+		self.connection_status.set_neq(status);
+	}
+}
+
+/// The characters the user is ignoring, by name.
+///
+/// Character names are case-insensitive in F-Chat, so they are stored and
+/// compared lowercased.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct IgnoreList {
+	#[serde(deserialize_with = "lowercase_names")]
+	characters: BTreeSet<String>,
+}
+
+impl IgnoreList {
+	/// Create a new, empty ignore list.
+	pub fn new() -> Self {
+		IgnoreList {
+			characters: BTreeSet::new(),
+		}
+	}
+
+	/// Start ignoring a character. Returns `false` if the character was
+	/// already being ignored.
+	pub fn add(&mut self, character: &str) -> bool {
+		self.characters.insert(character.to_lowercase())
+	}
+
+	/// Stop ignoring a character. Returns `false` if the character was not
+	/// being ignored.
+	pub fn remove(&mut self, character: &str) -> bool {
+		self.characters.remove(&character.to_lowercase())
+	}
+
+	/// Returns `true` if the character is being ignored.
+	pub fn contains(&self, character: &str) -> bool {
+		self.characters.contains(&character.to_lowercase())
+	}
+
+	/// Returns `false` if `command` is a channel or private message sent by
+	/// an ignored character.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::client::IgnoreList;
+	/// use snowcat::io::protocol::ServerCommand;
+	///
+	/// let mut ignore_list = IgnoreList::new();
+	/// ignore_list.add("Markelio");
+	///
+	/// let command = ServerCommand::from_wire(r#"PRI {"character":"Markelio","message":"hey"}"#)
+	/// 	.expect("command should be parsed successfully");
+	///
+	/// assert!(!ignore_list.allows(&command));
+	/// assert!(ignore_list.allows(&ServerCommand::Ping));
+	/// ```
+	pub fn allows(&self, command: &ServerCommand<'_>) -> bool {
+		match command {
+			ServerCommand::Message(message) => !self.contains(message.character()),
+			ServerCommand::PrivateMessage(message) => !self.contains(message.character()),
+			_ => true,
+		}
+	}
+
+	/// Iterate over the lowercased names of the ignored characters, in
+	/// alphabetical order.
+	pub fn iter(&self) -> impl Iterator<Item = &str> {
+		self.characters.iter().map(String::as_str)
+	}
+}
+
+fn lowercase_names<'de, D>(deserializer: D) -> Result<BTreeSet<String>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+{
+	let names = BTreeSet::<String>::deserialize(deserializer)?;
+	Ok(names.iter().map(|name| name.to_lowercase()).collect())
+}
+
+/// The state of the connection to the chat server.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum ConnectionStatus {
+	Connected,
+
+	/// The connection dropped, and is being retried for the `attempt`th time.
+	Reconnecting { attempt: u32 },
+
+	#[default]
+	Disconnected,
+}
+
+/// How long to wait between attempts to reconnect to the chat server.
+///
+/// The delay starts at `base` and doubles after every failed attempt, up to
+/// `cap`. Reconnecting is given up on after `max_attempts` attempts.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Backoff {
+	pub base: Duration,
+	pub cap: Duration,
+	pub max_attempts: u32,
+}
+
+impl Backoff {
+	/// Returns the delay before the given attempt, counting from 1, or `None`
+	/// if no more attempts should be made.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::client::Backoff;
+	/// use std::time::Duration;
+	///
+	/// let backoff = Backoff {
+	/// 	base: Duration::from_secs(1),
+	/// 	cap: Duration::from_secs(5),
+	/// 	max_attempts: 4,
+	/// };
+	///
+	/// assert_eq!(backoff.delay(1), Some(Duration::from_secs(1)));
+	/// assert_eq!(backoff.delay(3), Some(Duration::from_secs(4)));
+	/// assert_eq!(backoff.delay(4), Some(Duration::from_secs(5)));
+	/// assert_eq!(backoff.delay(5), None);
+	/// ```
+	pub fn delay(&self, attempt: u32) -> Option<Duration> {
+		if attempt == 0 || attempt > self.max_attempts {
+			return None;
+		}
+
+		let delay = 2u32.checked_pow(attempt - 1)
+			.and_then(|factor| self.base.checked_mul(factor))
+			.unwrap_or(self.cap);
+
+		Some(delay.min(self.cap))
+	}
+
+	/// Returns the delays before every attempt, in order.
+	pub fn schedule(&self) -> impl Iterator<Item = Duration> + '_ {
+		(1..).map_while(|attempt| self.delay(attempt))
+	}
+}
+
+impl Default for Backoff {
+	fn default() -> Self {
+		Backoff {
+			base: Duration::from_secs(1),
+			cap: Duration::from_secs(60),
+			max_attempts: 10,
+		}
+	}
+}
+
+pub struct ChannelList {
+	channels: BTreeMap<String, ChannelInfo>,
+
+	/// The IDs of the channels the user is in.
+	joined: BTreeSet<String>,
+}
 
 impl ChannelList {
 	/// Create a new, empty channel cache with the global allocator.
 	///
 	/// Does not allocate anything on its own.
 	pub fn new() -> Self {
-		ChannelList(BTreeMap::new())
+		ChannelList {
+			channels: BTreeMap::new(),
+			joined: BTreeSet::new(),
+		}
+	}
+
+	/// Insert a channel into the list, using its ID as the key.
+	///
+	/// Returns the channel ID to make future lookups easier.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::{ChannelList, ChannelListError};
+	///
+	/// let mut list = ChannelList::new();
+	///
+	/// let channel = ChannelInfo {
+	/// 	id: "Frontpage".to_owned(),
+	/// 	title: "Frontpage".to_owned(),
+	/// 	character_count: 1042,
+	/// 	is_official: true,
+	/// };
+	///
+	/// let id = list.insert(channel.clone()).expect("channel should be inserted successfully");
+	///
+	/// assert_eq!(list.get(&id), Some(&channel));
+	/// assert_eq!(list.insert(channel), Err(ChannelListError::ChannelAlreadyPresent(id)));
+	/// ```
+	pub fn insert(&mut self, channel_info: ChannelInfo) -> ChannelListResult<String> {
+		if self.channels.contains_key(&channel_info.id) {
+			return Err(ChannelListError::ChannelAlreadyPresent(channel_info.id));
+		}
+
+		let id = channel_info.id.clone();
+
+		self.channels.insert(id.clone(), channel_info);
+
+		Ok(id)
+	}
+
+	/// Get a reference to a channel by its ID.
+	pub fn get(&self, id: &str) -> Option<&ChannelInfo> {
+		self.channels.get(id)
+	}
+
+	/// Get a mutable reference to a channel by its ID.
+	///
+	/// To preserve ID-value relations, this API should never be used to alter
+	/// a channel's ID.
+	pub fn get_mut(&mut self, id: &str) -> Option<&mut ChannelInfo> {
+		self.channels.get_mut(id)
+	}
+
+	/// Remove a channel from the list, returning it.
+	///
+	/// The channel is no longer considered joined afterwards.
+	pub fn remove(&mut self, id: &str) -> ChannelListResult<ChannelInfo> {
+		let channel_info = self.channels.remove(id)
+			.ok_or_else(|| ChannelListError::ChannelNotFound(id.to_owned()))?;
+
+		self.joined.remove(id);
+		Ok(channel_info)
+	}
+
+	/// Fold the channels of `other` into this list, deciding which entry to
+	/// keep for channels present in both using `on_conflict`.
+	///
+	/// Channels joined in either list stay joined.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::{ChannelList, ConflictPolicy};
+	///
+	/// let mut official = ChannelList::new();
+	/// let mut private = ChannelList::new();
+	///
+	/// official.insert(ChannelInfo {
+	/// 	id: "Frontpage".to_owned(),
+	/// 	title: "Frontpage".to_owned(),
+	/// 	character_count: 1042,
+	/// 	is_official: true,
+	/// }).expect("channel should be inserted successfully");
+	///
+	/// private.insert(ChannelInfo {
+	/// 	id: "ADH-0001".to_owned(),
+	/// 	title: "Velvet Lounge".to_owned(),
+	/// 	character_count: 12,
+	/// 	is_official: false,
+	/// }).expect("channel should be inserted successfully");
+	///
+	/// official.merge(private, ConflictPolicy::PreferOfficial);
+	/// assert_eq!(official.iter().count(), 2);
+	/// ```
+	pub fn merge(&mut self, other: ChannelList, on_conflict: ConflictPolicy) {
+		for (id, channel_info) in other.channels {
+			match self.channels.get_mut(&id) {
+				None => {
+					self.channels.insert(id, channel_info);
+				},
+
+				Some(existing) => {
+					let overwrite = match on_conflict {
+						ConflictPolicy::KeepExisting => false,
+						ConflictPolicy::Overwrite => true,
+						ConflictPolicy::PreferOfficial => !existing.is_official && channel_info.is_official,
+					};
+
+					if overwrite {
+						*existing = channel_info;
+					}
+				},
+			}
+		}
+
+		self.joined.extend(other.joined);
+	}
+
+	/// Mark a channel in the list as joined or left.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::{ChannelList, ChannelListError};
+	///
+	/// let mut list = ChannelList::new();
+	///
+	/// list.insert(ChannelInfo {
+	/// 	id: "Frontpage".to_owned(),
+	/// 	title: "Frontpage".to_owned(),
+	/// 	character_count: 1042,
+	/// 	is_official: true,
+	/// }).expect("channel should be inserted successfully");
+	///
+	/// list.set_joined("Frontpage", true).expect("channel should be in the list");
+	/// assert!(list.is_joined("Frontpage"));
+	///
+	/// assert_eq!(
+	/// 	list.set_joined("Development", true),
+	/// 	Err(ChannelListError::ChannelNotFound("Development".to_owned())),
+	/// );
+	/// ```
+	pub fn set_joined(&mut self, id: &str, is_joined: bool) -> ChannelListResult<()> {
+		if !self.channels.contains_key(id) {
+			return Err(ChannelListError::ChannelNotFound(id.to_owned()));
+		}
+
+		if is_joined {
+			self.joined.insert(id.to_owned());
+		} else {
+			self.joined.remove(id);
+		}
+
+		Ok(())
+	}
+
+	/// Check whether the user is in a channel.
+	pub fn is_joined(&self, id: &str) -> bool {
+		self.joined.contains(id)
+	}
+
+	/// Iterate over the IDs of the channels the user is in, ordered by ID.
+	pub fn joined_ids(&self) -> impl Iterator<Item = &str> {
+		self.joined.iter().map(String::as_str)
+	}
+
+	/// Iterate over the channels in the list, ordered by ID.
+	pub fn iter(&self) -> impl Iterator<Item = &ChannelInfo> {
+		self.channels.values()
+	}
+
+	/// Iterate over the channels in the list, ordered by the key returned from
+	/// `key_fn`. Channels with equal keys are ordered by ID.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::ChannelList;
+	///
+	/// let mut list = ChannelList::new();
+	///
+	/// for (id, title) in [("ADH-0001", "Velvet Lounge"), ("ADH-0002", "Aurora Tavern")] {
+	/// 	list.insert(ChannelInfo {
+	/// 		id: id.to_owned(),
+	/// 		title: title.to_owned(),
+	/// 		character_count: 3,
+	/// 		is_official: false,
+	/// 	}).expect("channel should be inserted successfully");
+	/// }
+	///
+	/// let titles: Vec<_> = list.iter_sorted(|channel| &channel.title)
+	/// 	.map(|channel| channel.title.as_str())
+	/// 	.collect();
+	///
+	/// assert_eq!(titles, vec!["Aurora Tavern", "Velvet Lounge"]);
+	/// ```
+	pub fn iter_sorted<'list, T, F>(&'list self, mut key_fn: F) -> impl Iterator<Item = &'list ChannelInfo>
+	where T: Ord + ?Sized + 'list,
+	      F: FnMut(&'list ChannelInfo) -> &'list T,
+	{
+		let mut channels: Vec<_> = self.channels.values().collect();
+		channels.sort_by(|left, right| key_fn(left).cmp(key_fn(right)));
+
+		channels.into_iter()
+	}
+
+	/// Iterate over the channels in the list, ordered by `compare`. Channels
+	/// that compare equal are ordered by ID.
+	///
+	/// Unlike [`iter_sorted`](Self::iter_sorted), the order can depend on
+	/// values computed from the channels.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::ChannelList;
+	///
+	/// let mut list = ChannelList::new();
+	///
+	/// for (id, title) in [("ADH-0001", "velvet Lounge"), ("ADH-0002", "Aurora Tavern")] {
+	/// 	list.insert(ChannelInfo {
+	/// 		id: id.to_owned(),
+	/// 		title: title.to_owned(),
+	/// 		character_count: 3,
+	/// 		is_official: false,
+	/// 	}).expect("channel should be inserted successfully");
+	/// }
+	///
+	/// let titles: Vec<_> = list.iter_sorted_by(|left, right| left.title.to_lowercase().cmp(&right.title.to_lowercase()))
+	/// 	.map(|channel| channel.title.as_str())
+	/// 	.collect();
+	///
+	/// assert_eq!(titles, vec!["Aurora Tavern", "velvet Lounge"]);
+	/// ```
+	pub fn iter_sorted_by<F>(&self, mut compare: F) -> impl Iterator<Item = &ChannelInfo>
+	where F: FnMut(&ChannelInfo, &ChannelInfo) -> Ordering,
+	{
+		let mut channels: Vec<_> = self.channels.values().collect();
+		channels.sort_by(|left, right| compare(left, right));
+
+		channels.into_iter()
+	}
+
+	/// Iterate over the channels in the list, with the most populated
+	/// channels first. Channels with equal populations are ordered by ID.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::ChannelList;
+	///
+	/// let mut list = ChannelList::new();
+	///
+	/// for (id, character_count) in [("Development", 40), ("Frontpage", 1042), ("Helpdesk", 40)] {
+	/// 	list.insert(ChannelInfo {
+	/// 		id: id.to_owned(),
+	/// 		title: id.to_owned(),
+	/// 		character_count,
+	/// 		is_official: true,
+	/// 	}).expect("channel should be inserted successfully");
+	/// }
+	///
+	/// let ids: Vec<_> = list.iter_by_population()
+	/// 	.map(|channel| channel.id.as_str())
+	/// 	.collect();
+	///
+	/// assert_eq!(ids, vec!["Frontpage", "Development", "Helpdesk"]);
+	/// ```
+	pub fn iter_by_population(&self) -> impl Iterator<Item = &ChannelInfo> {
+		self.iter_sorted_by(|left, right| right.character_count.cmp(&left.character_count))
+	}
+
+	/// Iterate over the channels in the list, with the most populated
+	/// channels first. Kept for existing callers; see [`iter_by_population`].
+	///
+	/// [`iter_by_population`]: ChannelList::iter_by_population
+	pub fn iter_by_popularity(&self) -> impl Iterator<Item = &ChannelInfo> {
+		self.iter_by_population()
 	}
 }
 
@@ -30,9 +498,22 @@ impl Default for ChannelList {
 	}
 }
 
+/// Which entry [`ChannelList::merge`] keeps for a channel present in both
+/// lists.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ConflictPolicy {
+	KeepExisting,
+	Overwrite,
+
+	/// Keep whichever entry is for an official channel, or the existing entry
+	/// if both or neither are.
+	PreferOfficial,
+}
+
 pub struct CharacterList {
 	id_map: BTreeMap<CharacterId, String>,
 	name_map: BTreeMap<String, CharacterInfo>,
+	index: CharacterIndex,
 }
 
 impl CharacterList {
@@ -43,6 +524,69 @@ impl CharacterList {
 		CharacterList {
 			id_map: BTreeMap::new(),
 			name_map: BTreeMap::new(),
+			index: CharacterIndex::new(),
+		}
+	}
+
+	/// Returns the number of characters in the list.
+	pub fn len(&self) -> usize {
+		self.name_map.len()
+	}
+
+	/// Returns `true` if the list contains no characters.
+	pub fn is_empty(&self) -> bool {
+		self.name_map.is_empty()
+	}
+
+	/// Count the characters in the list by status. Statuses nobody has are
+	/// left out.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterList;
+	///
+	/// let mut list = CharacterList::new();
+	///
+	/// let characters = [
+	/// 	("Sarah Blitz Garisson", CharacterGender::Hermaphrodite, CharacterStatusKind::Looking),
+	/// 	("Markelio", CharacterGender::Male, CharacterStatusKind::Online),
+	/// 	("Aurelia", CharacterGender::Female, CharacterStatusKind::Online),
+	/// ];
+	///
+	/// list.bulk_insert_with_status(characters.into_iter().map(|(name, gender, status)| {
+	/// 	CharacterInfo {
+	/// 		name: name.to_owned(),
+	/// 		gender,
+	/// 		status: CharacterStatus::new(status),
+	/// 	}
+	/// }));
+	///
+	/// let counts = list.status_counts();
+	/// assert_eq!(counts.get(&CharacterStatusKind::Online), Some(&2));
+	/// assert_eq!(counts.get(&CharacterStatusKind::Looking), Some(&1));
+	/// assert_eq!(counts.get(&CharacterStatusKind::Busy), None);
+	/// ```
+	pub fn status_counts(&self) -> BTreeMap<CharacterStatusKind, usize> {
+		if self.index.is_stale {
+			count_from_list(&self.name_map, |character| character.status.kind)
+		} else {
+			count_from_index(&self.index.status)
+		}
+	}
+
+	/// Count the characters in the list by gender. Genders nobody has are
+	/// left out.
+	pub fn gender_counts(&self) -> BTreeMap<CharacterGender, usize> {
+		if self.index.is_stale {
+			count_from_list(&self.name_map, |character| character.gender)
+		} else {
+			count_from_index(&self.index.gender)
 		}
 	}
 
@@ -155,11 +699,74 @@ impl CharacterList {
 
 		let name = character_info.name.clone();
 
+		self.index.refresh(&self.name_map);
+		self.index.insert(&character_info);
 		self.name_map.insert(name.clone(), character_info);
 
 		Ok(name)
 	}
 
+	/// Insert many characters into the list at once, such as the contents of
+	/// the online list sent on login, and return how many were inserted.
+	///
+	/// Unlike [`insert`](CharacterList::insert), characters that are already
+	/// in the list are replaced, as the entries are assumed to be the latest
+	/// information sent by the server. Both the list and its status index are
+	/// built in a single pass.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterList;
+	///
+	/// let mut list = CharacterList::new();
+	///
+	/// let characters = [
+	/// 	("Sarah Blitz Garisson", CharacterGender::Hermaphrodite, CharacterStatusKind::Looking),
+	/// 	("Markelio", CharacterGender::Male, CharacterStatusKind::Online),
+	/// ];
+	///
+	/// let inserted = list.bulk_insert_with_status(characters.into_iter().map(|(name, gender, status)| {
+	/// 	CharacterInfo {
+	/// 		name: name.to_owned(),
+	/// 		gender,
+	/// 		status: CharacterStatus::new(status),
+	/// 	}
+	/// }));
+	///
+	/// assert_eq!(inserted, 2);
+	/// assert_eq!(list.len(), 2);
+	/// assert_eq!(
+	/// 	list.get_by_name("Markelio").map(|character| character.status.kind),
+	/// 	Some(CharacterStatusKind::Online),
+	/// );
+	/// ```
+	pub fn bulk_insert_with_status<I>(&mut self, entries: I) -> usize
+	where I: IntoIterator<Item = CharacterInfo>,
+	{
+		self.index.refresh(&self.name_map);
+
+		let mut inserted = 0;
+
+		for character_info in entries {
+			if let Some(previous) = self.name_map.remove(&character_info.name) {
+				self.index.remove(&previous);
+			}
+
+			self.index.insert(&character_info);
+			self.name_map.insert(character_info.name.clone(), character_info);
+
+			inserted += 1;
+		}
+
+		inserted
+	}
+
 	/// Insert an id-character pair into the list, using its name as the key.
 	///
 	/// Returns the character name to make future lookups easier.
@@ -246,6 +853,9 @@ impl CharacterList {
 	/// alter a character's name. Use [`set_name`](CharacterList::set_name)
 	/// instead.
 	///
+	/// Status changes made through this API force the status index to be
+	/// rebuilt; prefer [`set_status`](CharacterList::set_status) for them.
+	///
 	/// # Example
 	/// ```
 	/// use snowcat::api::characters::{
@@ -282,7 +892,10 @@ impl CharacterList {
 	/// ```
 	pub fn get_mut_by_id(&mut self, id: &CharacterId) -> Option<&mut CharacterInfo> {
 		let name = self.id_map.get(&id)?;
-		self.name_map.get_mut(name)
+		let character = self.name_map.get_mut(name)?;
+
+		self.index.mark_stale();
+		Some(character)
 	}
 
 	/// Acquire a reference by name to a character in the list.
@@ -321,6 +934,9 @@ impl CharacterList {
 	/// alter a character's name. Use [`set_name`](CharacterList::set_name)
 	/// instead.
 	///
+	/// Status changes made through this API force the status index to be
+	/// rebuilt; prefer [`set_status`](CharacterList::set_status) for them.
+	///
 	/// # Example
 	/// ```
 	/// use snowcat::api::characters::{
@@ -352,10 +968,17 @@ impl CharacterList {
 	/// );
 	/// ```
 	pub fn get_mut_by_name(&mut self, name: &str) -> Option<&mut CharacterInfo> {
-		self.name_map.get_mut(name)
+		let character = self.name_map.get_mut(name)?;
+
+		self.index.mark_stale();
+		Some(character)
 	}
 
-	/// Update a character's name.
+	/// Update a character's status.
+	///
+	/// Unlike changing the status through
+	/// [`get_mut_by_name`](CharacterList::get_mut_by_name), this keeps the
+	/// status index up to date without having to rebuild it.
 	///
 	/// # Example
 	/// ```
@@ -376,35 +999,32 @@ impl CharacterList {
 	/// 	status: CharacterStatus::new(CharacterStatusKind::Offline),
 	/// };
 	///
-	/// list.insert(character.clone()).expect("character should be inserted successfully");
+	/// list.insert(character).expect("character should be inserted successfully");
 	///
-	/// let new_name = "Markelio";
-	/// list.set_name(character_name, new_name).expect("character should exist under the correct name");
+	/// let status = CharacterStatus::new_with_message("just woke up...", CharacterStatusKind::DoNotDisturb);
+	/// list.set_status(character_name, status.clone()).expect("character should exist under the correct name");
 	///
-	/// assert_eq!(list.get_by_name(new_name).map(|character| &*character.name), Some(new_name));
-	/// assert_eq!(list.get_by_name(character_name), None);
+	/// assert_eq!(list.get_by_name(character_name).map(|character| &character.status), Some(&status));
 	/// ```
-	pub fn set_name(&mut self, character: &str, new_name: &str) -> CharacterListResult<()> {
-		let (mut character, character_id) = self.remove_by_name(character)?;
+	pub fn set_status(&mut self, character: &str, status: CharacterStatus) -> CharacterListResult<()> {
+		self.index.refresh(&self.name_map);
 
-		character.name = new_name.to_owned();
-
-		self.name_map.insert(new_name.to_owned(), character);
+		let character_info = self.name_map.get_mut(character)
+			.ok_or_else(|| CharacterListError::CharacterNotFound(character.to_owned()))?;
 
-		if let Some(character_id) = character_id {
-			self.id_map.insert(character_id, new_name.to_owned());
-		}
+		self.index.remove(character_info);
+		character_info.status = status;
+		self.index.insert(character_info);
 
 		Ok(())
 	}
 
-	/// Update a character's name using their ID.
+	/// Update a character's name.
 	///
 	/// # Example
 	/// ```
 	/// use snowcat::api::characters::{
 	/// 	CharacterGender,
-	/// 	CharacterId,
 	/// 	CharacterInfo,
 	/// 	CharacterStatus,
 	/// 	CharacterStatusKind,
@@ -420,8 +1040,53 @@ impl CharacterList {
 	/// 	status: CharacterStatus::new(CharacterStatusKind::Offline),
 	/// };
 	///
-	/// let character_id = 25565;
-	///
+	/// list.insert(character.clone()).expect("character should be inserted successfully");
+	///
+	/// let new_name = "Markelio";
+	/// list.set_name(character_name, new_name).expect("character should exist under the correct name");
+	///
+	/// assert_eq!(list.get_by_name(new_name).map(|character| &*character.name), Some(new_name));
+	/// assert_eq!(list.get_by_name(character_name), None);
+	/// ```
+	pub fn set_name(&mut self, character: &str, new_name: &str) -> CharacterListResult<()> {
+		let (mut character, character_id) = self.remove_by_name(character)?;
+
+		character.name = new_name.to_owned();
+
+		self.index.insert(&character);
+		self.name_map.insert(new_name.to_owned(), character);
+
+		if let Some(character_id) = character_id {
+			self.id_map.insert(character_id, new_name.to_owned());
+		}
+
+		Ok(())
+	}
+
+	/// Update a character's name using their ID.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterId,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterList;
+	///
+	/// let mut list = CharacterList::new();
+	///
+	/// let character_name = "Sarah Blitz Garisson";
+	/// let character = CharacterInfo {
+	/// 	name: character_name.to_owned(),
+	/// 	gender: CharacterGender::Hermaphrodite,
+	/// 	status: CharacterStatus::new(CharacterStatusKind::Offline),
+	/// };
+	///
+	/// let character_id = 25565;
+	///
 	/// list.insert(character.clone()).expect("character should be inserted successfully");
 	/// list.associate_id(character_name, CharacterId(character_id)).expect("id association should be successful");
 	///
@@ -437,6 +1102,7 @@ impl CharacterList {
 
 		character.name = new_name.to_owned();
 
+		self.index.insert(&character);
 		self.name_map.insert(new_name.to_owned(), character);
 		self.id_map.insert(id, new_name.to_owned());
 
@@ -483,6 +1149,9 @@ impl CharacterList {
 		let character = self.name_map.remove(&character_name)
 			.expect("character has a valid ID, and therefore should be present");
 
+		self.index.refresh(&self.name_map);
+		self.index.remove(&character);
+
 		Ok(character)
 	}
 
@@ -527,8 +1196,235 @@ impl CharacterList {
 			self.id_map.remove(&character_id);
 		}
 
+		self.index.refresh(&self.name_map);
+		self.index.remove(&character);
+
 		Ok((character, character_id))
 	}
+
+	/// Iterate mutably over every character in the list, in name order.
+	///
+	/// Characters are keyed by name, so the entries handed out only allow
+	/// changing a character's gender and status. Use
+	/// [`set_name`](Self::set_name) to rename a character.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterList;
+	///
+	/// let mut list = CharacterList::new();
+	///
+	/// list.insert(CharacterInfo {
+	/// 	name: "Sarah Blitz Garisson".to_owned(),
+	/// 	gender: CharacterGender::Hermaphrodite,
+	/// 	status: CharacterStatus::new(CharacterStatusKind::Online),
+	/// }).expect("character should be inserted successfully");
+	///
+	/// for mut character in list.iter_mut() {
+	/// 	*character.status_mut() = CharacterStatus::new(CharacterStatusKind::Offline);
+	/// }
+	///
+	/// assert_eq!(
+	/// 	list.get_by_name("Sarah Blitz Garisson").map(|character| character.status.kind),
+	/// 	Some(CharacterStatusKind::Offline),
+	/// );
+	/// ```
+	pub fn iter_mut(&mut self) -> impl Iterator<Item = CharacterEntryMut<'_>> {
+		self.index.mark_stale();
+		self.name_map.values_mut().map(|character| CharacterEntryMut { character })
+	}
+
+	/// Only keep the characters for which `keep` returns `true`, along with
+	/// their IDs.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterId,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterList;
+	///
+	/// let mut list = CharacterList::new();
+	///
+	/// for (name, kind) in [("Sarah Blitz Garisson", CharacterStatusKind::Offline), ("Markelio", CharacterStatusKind::Online)] {
+	/// 	list.insert(CharacterInfo {
+	/// 		name: name.to_owned(),
+	/// 		gender: CharacterGender::Hermaphrodite,
+	/// 		status: CharacterStatus::new(kind),
+	/// 	}).expect("character should be inserted successfully");
+	/// }
+	///
+	/// list.associate_id("Sarah Blitz Garisson", CharacterId(25565)).expect("id association should be successful");
+	/// list.retain(|character| character.status.kind != CharacterStatusKind::Offline);
+	///
+	/// assert_eq!(list.len(), 1);
+	/// assert_eq!(list.get_by_id(&CharacterId(25565)), None);
+	/// ```
+	pub fn retain<F>(&mut self, mut keep: F)
+	where F: FnMut(&CharacterInfo) -> bool,
+	{
+		self.index.refresh(&self.name_map);
+
+		let index = &mut self.index;
+		self.name_map.retain(|_, character| {
+			let is_kept = keep(character);
+
+			if !is_kept {
+				index.remove(character);
+			}
+
+			is_kept
+		});
+
+		// IDs are keyed by ID rather than by name, so any ID left pointing at a
+		// removed character has to be found by its value
+		let name_map = &self.name_map;
+		self.id_map.retain(|_, name| name_map.contains_key(name));
+	}
+
+	/// Remove every character for which `filter` returns `true`, along with
+	/// their IDs, and return them.
+	///
+	/// Unlike [`retain`](Self::retain), `filter` returns `true` for the
+	/// characters to remove.
+	pub fn drain_filter<F>(&mut self, mut filter: F) -> Vec<CharacterInfo>
+	where F: FnMut(&CharacterInfo) -> bool,
+	{
+		self.index.refresh(&self.name_map);
+
+		let (removed, kept): (BTreeMap<_, _>, BTreeMap<_, _>) = std::mem::take(&mut self.name_map)
+			.into_iter()
+			.partition(|(_, character)| filter(character));
+
+		self.name_map = kept;
+
+		let name_map = &self.name_map;
+		self.id_map.retain(|_, name| name_map.contains_key(name));
+
+		removed.into_values()
+			.inspect(|character| self.index.remove(character))
+			.collect()
+	}
+}
+
+/// A mutable reference to a character in a [`CharacterList`] that cannot
+/// change the character's name.
+#[derive(Debug)]
+pub struct CharacterEntryMut<'list> {
+	character: &'list mut CharacterInfo,
+}
+
+impl CharacterEntryMut<'_> {
+	pub fn gender_mut(&mut self) -> &mut CharacterGender {
+		&mut self.character.gender
+	}
+
+	pub fn status_mut(&mut self) -> &mut CharacterStatus {
+		&mut self.character.status
+	}
+}
+
+impl Deref for CharacterEntryMut<'_> {
+	type Target = CharacterInfo;
+
+	fn deref(&self) -> &Self::Target {
+		self.character
+	}
+}
+
+/// Secondary indexes over the characters in a [`CharacterList`].
+///
+/// Handing out a mutable reference to a character may change any of the
+/// indexed fields behind the index's back, so doing so marks the index as
+/// stale. A stale index is rebuilt on the next change made through the list,
+/// and is never read from until then.
+#[derive(Debug)]
+struct CharacterIndex {
+	gender: BTreeMap<CharacterGender, BTreeSet<String>>,
+	status: BTreeMap<CharacterStatusKind, BTreeSet<String>>,
+	is_stale: bool,
+}
+
+impl CharacterIndex {
+	fn new() -> Self {
+		CharacterIndex {
+			gender: BTreeMap::new(),
+			status: BTreeMap::new(),
+			is_stale: false,
+		}
+	}
+
+	fn insert(&mut self, character: &CharacterInfo) {
+		insert_into(&mut self.gender, character.gender, &character.name);
+		insert_into(&mut self.status, character.status.kind, &character.name);
+	}
+
+	fn remove(&mut self, character: &CharacterInfo) {
+		remove_from(&mut self.gender, &character.gender, &character.name);
+		remove_from(&mut self.status, &character.status.kind, &character.name);
+	}
+
+	fn mark_stale(&mut self) {
+		self.is_stale = true;
+	}
+
+	fn refresh(&mut self, name_map: &BTreeMap<String, CharacterInfo>) {
+		if !self.is_stale {
+			return;
+		}
+
+		self.gender.clear();
+		self.status.clear();
+		name_map.values().for_each(|character| self.insert(character));
+
+		self.is_stale = false;
+	}
+}
+
+fn insert_into<K>(index: &mut BTreeMap<K, BTreeSet<String>>, key: K, name: &str)
+where K: Ord,
+{
+	index.entry(key)
+		.or_default()
+		.insert(name.to_owned());
+}
+
+fn remove_from<K>(index: &mut BTreeMap<K, BTreeSet<String>>, key: &K, name: &str)
+where K: Ord,
+{
+	if let Some(names) = index.get_mut(key) {
+		names.remove(name);
+
+		if names.is_empty() {
+			index.remove(key);
+		}
+	}
+}
+
+fn count_from_index<K>(index: &BTreeMap<K, BTreeSet<String>>) -> BTreeMap<K, usize>
+where K: Ord + Copy,
+{
+	index.iter().map(|(&key, names)| (key, names.len())).collect()
+}
+
+fn count_from_list<K, F>(name_map: &BTreeMap<String, CharacterInfo>, key_fn: F) -> BTreeMap<K, usize>
+where K: Ord,
+      F: Fn(&CharacterInfo) -> K,
+{
+	name_map.values().fold(BTreeMap::new(), |mut counts, character| {
+		*counts.entry(key_fn(character)).or_default() += 1;
+		counts
+	})
 }
 
 impl Default for CharacterList {
@@ -573,6 +1469,190 @@ where
 	}
 }
 
+/// A character cache the frontend can subscribe to.
+///
+/// Unlike [`CharacterList`], characters are keyed by their ID, and every
+/// change is broadcast through [`signal_map_cloned`](Self::signal_map_cloned)
+/// as the smallest [`MapDiff`](futures_signals::signal_map::MapDiff)
+/// describing it, so a status change only touches the one character it
+/// belongs to.
+pub struct CharacterRoster {
+	characters: MutableBTreeMap<CharacterId, CharacterInfo>,
+	names: RwLock<BTreeMap<String, CharacterId>>,
+}
+
+impl CharacterRoster {
+	/// Create a new, empty roster.
+	pub fn new() -> Self {
+		CharacterRoster {
+			characters: MutableBTreeMap::new(),
+			names: RwLock::new(BTreeMap::new()),
+		}
+	}
+
+	/// Returns the number of characters in the roster.
+	pub fn len(&self) -> usize {
+		self.characters.lock_ref().len()
+	}
+
+	/// Returns `true` if the roster contains no characters.
+	pub fn is_empty(&self) -> bool {
+		self.characters.lock_ref().is_empty()
+	}
+
+	/// Get a copy of a character using their ID.
+	pub fn get_by_id(&self, id: &CharacterId) -> Option<CharacterInfo> {
+		self.characters.lock_ref().get(id).cloned()
+	}
+
+	/// Get a copy of a character using their name.
+	pub fn get_by_name(&self, character: &str) -> Option<CharacterInfo> {
+		self.id_of(character).and_then(|id| self.get_by_id(&id))
+	}
+
+	/// Get the ID of a character using their name.
+	pub fn id_of(&self, character: &str) -> Option<CharacterId> {
+		self.names.read().expect("name index should not be poisoned").get(character).copied()
+	}
+
+	/// Attempt to insert a character under the given ID. Fails if either the
+	/// ID or the character's name are already present.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterId,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::{
+	/// 	CharacterListError,
+	/// 	CharacterRoster,
+	/// };
+	///
+	/// let roster = CharacterRoster::new();
+	///
+	/// let character_name = "Sarah Blitz Garisson";
+	/// let character = CharacterInfo {
+	/// 	name: character_name.to_owned(),
+	/// 	gender: CharacterGender::Hermaphrodite,
+	/// 	status: CharacterStatus::new(CharacterStatusKind::Offline),
+	/// };
+	///
+	/// let character_id = CharacterId(25565);
+	///
+	/// roster.insert(character_id, character.clone()).expect("character should be inserted successfully");
+	/// assert_eq!(roster.id_of(character_name), Some(character_id));
+	///
+	/// assert_eq!(roster.insert(character_id, character), Err(CharacterListError::IdAlreadyExists(character_id)));
+	/// ```
+	pub fn insert(&self, id: CharacterId, character: CharacterInfo) -> CharacterListResult<()> {
+		// the name index is always locked before the characters, and held
+		// until both have been changed
+		let mut names = self.names.write().expect("name index should not be poisoned");
+		let mut characters = self.characters.lock_mut();
+
+		if characters.get(&id).is_some() {
+			return Err(CharacterListError::IdAlreadyExists(id));
+		}
+
+		if names.contains_key(&character.name) {
+			return Err(CharacterListError::CharacterAlreadyPresent(character.name));
+		}
+
+		names.insert(character.name.clone(), id);
+		characters.insert_cloned(id, character);
+
+		Ok(())
+	}
+
+	/// Remove a character from the roster using their ID.
+	pub fn remove_by_id(&self, id: CharacterId) -> CharacterListResult<CharacterInfo> {
+		let mut names = self.names.write().expect("name index should not be poisoned");
+		let mut characters = self.characters.lock_mut();
+
+		let character = characters.remove(&id).ok_or(CharacterListError::IdNotFound(id))?;
+		names.remove(&character.name);
+
+		Ok(character)
+	}
+
+	/// Remove a character from the roster using their name.
+	pub fn remove_by_name(&self, character: &str) -> CharacterListResult<(CharacterInfo, CharacterId)> {
+		let id = self.id_of(character).ok_or_else(|| CharacterListError::CharacterNotFound(character.to_owned()))?;
+
+		self.remove_by_id(id).map(|character| (character, id))
+	}
+
+	/// Update a character's status.
+	///
+	/// Subscribers are sent a single
+	/// [`MapDiff::Update`](futures_signals::signal_map::MapDiff::Update) for
+	/// the character.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::characters::{
+	/// 	CharacterGender,
+	/// 	CharacterId,
+	/// 	CharacterInfo,
+	/// 	CharacterStatus,
+	/// 	CharacterStatusKind,
+	/// };
+	/// use snowcat::client::CharacterRoster;
+	///
+	/// let roster = CharacterRoster::new();
+	///
+	/// let character_id = CharacterId(25565);
+	/// let character = CharacterInfo {
+	/// 	name: "Sarah Blitz Garisson".to_owned(),
+	/// 	gender: CharacterGender::Hermaphrodite,
+	/// 	status: CharacterStatus::new(CharacterStatusKind::Offline),
+	/// };
+	///
+	/// roster.insert(character_id, character).expect("character should be inserted successfully");
+	///
+	/// let status = CharacterStatus::new_with_message("just woke up...", CharacterStatusKind::DoNotDisturb);
+	/// roster.update_status(character_id, status.clone()).expect("character should exist under the correct ID");
+	///
+	/// assert_eq!(roster.get_by_id(&character_id).map(|character| character.status), Some(status));
+	/// ```
+	pub fn update_status(&self, id: CharacterId, status: CharacterStatus) -> CharacterListResult<()> {
+		let mut characters = self.characters.lock_mut();
+
+		let mut character = characters.get(&id).cloned().ok_or(CharacterListError::IdNotFound(id))?;
+		character.status = status;
+
+		// the key is already present, so this is sent as an update
+		characters.insert_cloned(id, character);
+
+		Ok(())
+	}
+
+	/// Returns a signal of every change made to the roster, starting with its
+	/// current contents.
+	pub fn signal_map_cloned(&self) -> MutableSignalMap<CharacterId, CharacterInfo> {
+		self.characters.signal_map_cloned()
+	}
+}
+
+impl Default for CharacterRoster {
+	fn default() -> Self {
+		CharacterRoster::new()
+	}
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub enum ChannelListError {
+	#[error("The channel {0:?} is already in the list.")]
+	ChannelAlreadyPresent(String),
+
+	#[error("Did not find channel {0:?} in the list.")]
+	ChannelNotFound(String),
+}
+
 #[derive(Debug, Clone, Error, Eq, PartialEq)]
 pub enum CharacterListError {
 	#[error("The character {0:?} is already associated with ID {1}")]
@@ -593,3 +1673,518 @@ pub enum CharacterListError {
 	#[error("Cannot acquire a mutable reference to character {0:?} without violating memory safety rules")]
 	CannotMutate(String),
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+	use crate::api::channels::ChannelInfo;
+	use crate::api::characters::{CharacterGender, CharacterId, CharacterInfo, CharacterStatus, CharacterStatusKind};
+	use crate::io::protocol::ServerCommand;
+	use futures::task::noop_waker_ref;
+	use futures_signals::signal_map::{MapDiff, SignalMapExt};
+	use std::task::{Context, Poll};
+	use std::time::Duration;
+	use super::{
+		Backoff,
+		ChannelList,
+		ChannelListError,
+		CharacterList,
+		CharacterListError,
+		CharacterRoster,
+		ConflictPolicy,
+		IgnoreList,
+	};
+
+	const STATUSES: [CharacterStatusKind; 4] = [
+		CharacterStatusKind::Online,
+		CharacterStatusKind::Looking,
+		CharacterStatusKind::Busy,
+		CharacterStatusKind::Away,
+	];
+
+	fn online_list(count: usize) -> impl Iterator<Item = CharacterInfo> {
+		(0..count).map(|index| CharacterInfo {
+			name: format!("Character {index}"),
+			gender: CharacterGender::None,
+			status: CharacterStatus::new(STATUSES[index % STATUSES.len()]),
+		})
+	}
+
+	fn assert_index_consistent(list: &CharacterList) {
+		assert!(!list.index.is_stale, "index should have been refreshed");
+
+		let indexed: usize = list.index.status.values().map(|names| names.len()).sum();
+		assert_eq!(indexed, list.len());
+
+		for (kind, names) in &list.index.status {
+			for name in names {
+				assert_eq!(list.get_by_name(name).map(|character| character.status.kind), Some(*kind));
+			}
+		}
+	}
+
+	#[test]
+	fn bulk_insert_large_online_list() {
+		let mut list = CharacterList::new();
+		let inserted = list.bulk_insert_with_status(online_list(5000));
+
+		assert_eq!(inserted, 5000);
+		assert_eq!(list.len(), 5000);
+		assert_eq!(list.index.status.len(), STATUSES.len());
+
+		for kind in STATUSES {
+			assert_eq!(list.index.status[&kind].len(), 1250);
+		}
+
+		assert_index_consistent(&list);
+	}
+
+	#[test]
+	fn bulk_insert_replaces_existing() {
+		let mut list = CharacterList::new();
+		list.bulk_insert_with_status(online_list(4000));
+
+		// the same characters, all of whom have since gone idle
+		let inserted = list.bulk_insert_with_status(online_list(4000).map(|character| CharacterInfo {
+			status: CharacterStatus::new(CharacterStatusKind::Idle),
+			..character
+		}));
+
+		assert_eq!(inserted, 4000);
+		assert_eq!(list.len(), 4000);
+		assert_eq!(list.index.status.len(), 1);
+		assert_eq!(list.index.status[&CharacterStatusKind::Idle].len(), 4000);
+
+		assert_index_consistent(&list);
+	}
+
+	#[test]
+	fn counts_after_inserts_and_status_updates() {
+		let mut list = CharacterList::new();
+		list.bulk_insert_with_status(online_list(12));
+
+		let sarah = CharacterInfo {
+			name: "Sarah Blitz Garisson".to_owned(),
+			gender: CharacterGender::Hermaphrodite,
+			status: CharacterStatus::new(CharacterStatusKind::Looking),
+		};
+
+		list.insert(sarah).expect("character should be inserted successfully");
+
+		let status_counts = list.status_counts();
+		assert_eq!(status_counts[&CharacterStatusKind::Online], 3);
+		assert_eq!(status_counts[&CharacterStatusKind::Looking], 4);
+		assert_eq!(status_counts[&CharacterStatusKind::Busy], 3);
+		assert_eq!(status_counts[&CharacterStatusKind::Away], 3);
+
+		// statuses are counted in display order
+		assert_eq!(status_counts.keys().copied().collect::<Vec<_>>(), [
+			CharacterStatusKind::Looking,
+			CharacterStatusKind::Online,
+			CharacterStatusKind::Away,
+			CharacterStatusKind::Busy,
+		]);
+
+		let gender_counts = list.gender_counts();
+		assert_eq!(gender_counts[&CharacterGender::None], 12);
+		assert_eq!(gender_counts[&CharacterGender::Hermaphrodite], 1);
+
+		list.set_status("Sarah Blitz Garisson", CharacterStatus::new(CharacterStatusKind::Busy))
+			.expect("character should exist under the correct name");
+
+		list.set_status("Character 0", CharacterStatus::new(CharacterStatusKind::Busy))
+			.expect("character should exist under the correct name");
+
+		let status_counts = list.status_counts();
+		assert_eq!(status_counts[&CharacterStatusKind::Online], 2);
+		assert_eq!(status_counts[&CharacterStatusKind::Looking], 3);
+		assert_eq!(status_counts[&CharacterStatusKind::Busy], 5);
+
+		// changes made through a mutable reference are counted as well
+		if let Some(character) = list.get_mut_by_name("Character 1") {
+			character.status = CharacterStatus::new(CharacterStatusKind::Idle);
+		}
+
+		let status_counts = list.status_counts();
+		assert_eq!(status_counts[&CharacterStatusKind::Looking], 2);
+		assert_eq!(status_counts[&CharacterStatusKind::Idle], 1);
+
+		list.remove_by_name("Character 1").expect("character should exist under the correct name");
+		assert_eq!(list.status_counts().get(&CharacterStatusKind::Idle), None);
+		assert_index_consistent(&list);
+	}
+
+	#[test]
+	fn index_rebuilt_after_mutable_access() {
+		let mut list = CharacterList::new();
+		list.bulk_insert_with_status(online_list(8));
+
+		if let Some(character) = list.get_mut_by_name("Character 0") {
+			character.status = CharacterStatus::new(CharacterStatusKind::Idle);
+		}
+
+		assert!(list.index.is_stale);
+
+		list.set_status("Character 1", CharacterStatus::new(CharacterStatusKind::Idle))
+			.expect("character should exist under the correct name");
+
+		assert_eq!(list.index.status[&CharacterStatusKind::Idle].len(), 2);
+		assert_index_consistent(&list);
+	}
+
+	#[test]
+	fn iter_mut_resets_statuses() {
+		let mut list = CharacterList::new();
+		list.bulk_insert_with_status(online_list(6));
+
+		for mut character in list.iter_mut() {
+			*character.status_mut() = CharacterStatus::new(CharacterStatusKind::Offline);
+		}
+
+		assert!(list.name_map.values().all(|character| character.status.kind == CharacterStatusKind::Offline));
+		assert_eq!(list.status_counts().get(&CharacterStatusKind::Offline), Some(&6));
+
+		// the index is rebuilt on the next change
+		list.set_status("Character 0", CharacterStatus::new(CharacterStatusKind::Online))
+			.expect("character should exist under the correct name");
+
+		assert_index_consistent(&list);
+	}
+
+	fn list_with_ids(count: usize) -> CharacterList {
+		let mut list = CharacterList::new();
+
+		for (index, character) in online_list(count).enumerate() {
+			let name = list.insert(character).expect("character should be inserted successfully");
+
+			// only every other character has an ID
+			if index % 2 == 0 {
+				list.associate_id(&name, CharacterId(index as u64)).expect("id association should be successful");
+			}
+		}
+
+		list
+	}
+
+	fn assert_ids_consistent(list: &CharacterList) {
+		for (id, name) in &list.id_map {
+			assert!(list.name_map.contains_key(name), "ID {id} points at missing character {name:?}");
+		}
+	}
+
+	#[test]
+	fn retain_removes_orphaned_ids() {
+		let mut list = list_with_ids(8);
+
+		// keeps characters 0, 1, 4 and 5
+		list.retain(|character| matches!(character.status.kind, CharacterStatusKind::Online | CharacterStatusKind::Looking));
+
+		assert_eq!(list.len(), 4);
+		assert_eq!(list.id_map.keys().copied().collect::<Vec<_>>(), [CharacterId(0), CharacterId(4)]);
+		assert_ids_consistent(&list);
+		assert_index_consistent(&list);
+	}
+
+	#[test]
+	fn drain_filter_returns_removed() {
+		let mut list = list_with_ids(6);
+		list.get_mut_by_name("Character 3").expect("character should exist").status.kind = CharacterStatusKind::Online;
+
+		let removed = list.drain_filter(|character| character.status.kind == CharacterStatusKind::Online);
+		let names: Vec<_> = removed.iter().map(|character| &*character.name).collect();
+
+		assert_eq!(names, ["Character 0", "Character 3", "Character 4"]);
+		assert_eq!(list.len(), 3);
+		assert_eq!(list.id_map.keys().copied().collect::<Vec<_>>(), [CharacterId(2)]);
+		assert_ids_consistent(&list);
+		assert_index_consistent(&list);
+	}
+
+	/// Official channels, as `(id, title, character_count, is_official)`.
+	pub(crate) const OFFICIAL_CHANNELS: [(&str, &str, u64, bool); 3] = [
+		("Frontpage", "Frontpage", 0, true),
+		("Development", "Development", 0, true),
+		("Helpdesk", "Helpdesk", 0, true),
+	];
+
+	/// Builds a channel list from `(id, title, character_count, is_official)`
+	/// entries.
+	pub(crate) fn channel_list(channels: &[(&str, &str, u64, bool)]) -> ChannelList {
+		let mut list = ChannelList::new();
+
+		for &(id, title, character_count, is_official) in channels {
+			list.insert(ChannelInfo {
+				id: id.to_owned(),
+				title: title.to_owned(),
+				character_count,
+				is_official,
+			}).expect("channel should be inserted successfully");
+		}
+
+		list
+	}
+
+	const POPULATED_CHANNELS: [(&str, &str, u64, bool); 3] = [
+		("ADH-0001", "velvet Lounge", 12, false),
+		("ADH-0002", "Aurora Tavern", 85, false),
+		("ADH-0003", "Midnight Market", 12, false),
+	];
+
+	#[test]
+	fn iter_by_population_descending() {
+		let list = channel_list(&POPULATED_CHANNELS);
+		let ids: Vec<_> = list.iter_by_population().map(|channel| channel.id.as_str()).collect();
+
+		// equal populations keep ID order
+		assert_eq!(ids, ["ADH-0002", "ADH-0001", "ADH-0003"]);
+	}
+
+	#[test]
+	fn iter_sorted_by_case_insensitive_title() {
+		let list = channel_list(&POPULATED_CHANNELS);
+		let titles: Vec<_> = list.iter_sorted_by(|left, right| left.title.to_lowercase().cmp(&right.title.to_lowercase()))
+			.map(|channel| channel.title.as_str())
+			.collect();
+
+		assert_eq!(titles, ["Aurora Tavern", "Midnight Market", "velvet Lounge"]);
+	}
+
+	fn merged_titles(on_conflict: ConflictPolicy) -> Vec<String> {
+		let mut list = channel_list(&[("Frontpage", "Frontpage", 0, true), ("Hideout", "Hideout (old)", 0, false)]);
+		let other = channel_list(&[
+			("Frontpage", "Frontpage (room)", 0, false),
+			("Hideout", "Hideout", 0, true),
+			("Lounge", "Lounge", 0, false),
+		]);
+
+		list.merge(other, on_conflict);
+		list.iter().map(|channel| channel.title.clone()).collect()
+	}
+
+	#[test]
+	fn merge_keep_existing() {
+		assert_eq!(merged_titles(ConflictPolicy::KeepExisting), ["Frontpage", "Hideout (old)", "Lounge"]);
+	}
+
+	#[test]
+	fn merge_overwrite() {
+		assert_eq!(merged_titles(ConflictPolicy::Overwrite), ["Frontpage (room)", "Hideout", "Lounge"]);
+	}
+
+	#[test]
+	fn merge_prefer_official() {
+		assert_eq!(merged_titles(ConflictPolicy::PreferOfficial), ["Frontpage", "Hideout", "Lounge"]);
+	}
+
+	#[test]
+	fn merge_keeps_joined_channels() {
+		let mut list = channel_list(&[("Frontpage", "Frontpage", 0, true)]);
+		let mut other = channel_list(&[("Frontpage", "Frontpage", 0, true), ("Lounge", "Lounge", 0, false)]);
+
+		list.set_joined("Frontpage", true).expect("channel should be in the list");
+		other.set_joined("Lounge", true).expect("channel should be in the list");
+
+		list.merge(other, ConflictPolicy::Overwrite);
+		assert_eq!(list.joined_ids().collect::<Vec<_>>(), ["Frontpage", "Lounge"]);
+	}
+
+	#[test]
+	fn joined_state_toggles() {
+		let mut list = channel_list(&OFFICIAL_CHANNELS);
+		assert_eq!(list.joined_ids().count(), 0);
+
+		list.set_joined("Helpdesk", true).expect("channel should be in the list");
+		list.set_joined("Frontpage", true).expect("channel should be in the list");
+		assert_eq!(list.joined_ids().collect::<Vec<_>>(), ["Frontpage", "Helpdesk"]);
+
+		list.set_joined("Frontpage", false).expect("channel should be in the list");
+		assert!(!list.is_joined("Frontpage"));
+		assert_eq!(list.joined_ids().collect::<Vec<_>>(), ["Helpdesk"]);
+
+		assert_eq!(
+			list.set_joined("Gone", true),
+			Err(ChannelListError::ChannelNotFound("Gone".to_owned())),
+		);
+	}
+
+	#[test]
+	fn joined_state_cleared_on_remove() {
+		let mut list = channel_list(&OFFICIAL_CHANNELS);
+		list.set_joined("Development", true).expect("channel should be in the list");
+
+		list.remove("Development").expect("channel should be in the list");
+		assert_eq!(list.joined_ids().count(), 0);
+
+		// rejoining a channel that was removed and listed again starts fresh
+		list.insert(ChannelInfo {
+			id: "Development".to_owned(),
+			title: "Development".to_owned(),
+			character_count: 0,
+			is_official: true,
+		}).expect("channel should be inserted successfully");
+
+		assert!(!list.is_joined("Development"));
+	}
+
+	#[test]
+	fn roster_status_change_is_single_update() {
+		let roster = CharacterRoster::new();
+
+		for (index, character) in online_list(3).enumerate() {
+			roster.insert(CharacterId(index as u64), character).expect("character should be inserted successfully");
+		}
+
+		let mut signal = roster.signal_map_cloned();
+		let mut cx = Context::from_waker(noop_waker_ref());
+
+		assert!(matches!(signal.poll_map_change_unpin(&mut cx), Poll::Ready(Some(MapDiff::Replace { .. }))));
+		assert_eq!(signal.poll_map_change_unpin(&mut cx), Poll::Pending);
+
+		let status = CharacterStatus::new_with_message("brb", CharacterStatusKind::Away);
+		roster.update_status(CharacterId(1), status.clone()).expect("character should exist under the correct ID");
+
+		match signal.poll_map_change_unpin(&mut cx) {
+			Poll::Ready(Some(MapDiff::Update { key, value })) => {
+				assert_eq!(key, CharacterId(1));
+				assert_eq!(value.status, status);
+			},
+
+			change => panic!("expected a single update, got {change:?}"),
+		}
+
+		assert_eq!(signal.poll_map_change_unpin(&mut cx), Poll::Pending);
+
+		assert_eq!(
+			roster.update_status(CharacterId(3), status),
+			Err(CharacterListError::IdNotFound(CharacterId(3))),
+		);
+	}
+
+	#[test]
+	fn roster_keeps_names_indexed() {
+		let roster = CharacterRoster::new();
+
+		for (index, character) in online_list(2).enumerate() {
+			roster.insert(CharacterId(index as u64), character).expect("character should be inserted successfully");
+		}
+
+		assert_eq!(roster.id_of("Character 1"), Some(CharacterId(1)));
+		assert_eq!(
+			roster.insert(CharacterId(2), online_list(1).next().unwrap()),
+			Err(CharacterListError::CharacterAlreadyPresent("Character 0".to_owned())),
+		);
+
+		let (_, id) = roster.remove_by_name("Character 0").expect("character should exist under the correct name");
+		assert_eq!(id, CharacterId(0));
+		assert_eq!(roster.id_of("Character 0"), None);
+		assert_eq!(roster.len(), 1);
+	}
+
+	#[test]
+	fn backoff_doubles_up_to_cap() {
+		let backoff = Backoff {
+			base: Duration::from_millis(500),
+			cap: Duration::from_secs(8),
+			max_attempts: 7,
+		};
+
+		let schedule: Vec<_> = backoff.schedule().map(|delay| delay.as_millis()).collect();
+		assert_eq!(schedule, [500, 1000, 2000, 4000, 8000, 8000, 8000]);
+
+		assert_eq!(backoff.delay(0), None);
+		assert_eq!(backoff.delay(8), None);
+	}
+
+	#[test]
+	fn backoff_saturates_at_cap() {
+		let backoff = Backoff {
+			base: Duration::from_secs(1),
+			cap: Duration::from_secs(300),
+			max_attempts: u32::MAX,
+		};
+
+		// the doubling factor no longer fits in a u32
+		assert_eq!(backoff.delay(64), Some(Duration::from_secs(300)));
+		assert_eq!(backoff.delay(u32::MAX), Some(Duration::from_secs(300)));
+	}
+
+	#[test]
+	fn ignore_list_is_idempotent() {
+		let mut ignore_list = IgnoreList::new();
+
+		assert!(ignore_list.add("Markelio"));
+		assert!(!ignore_list.add("Markelio"));
+		assert_eq!(ignore_list.iter().collect::<Vec<_>>(), ["markelio"]);
+
+		assert!(ignore_list.remove("Markelio"));
+		assert!(!ignore_list.remove("Markelio"));
+		assert!(!ignore_list.contains("Markelio"));
+	}
+
+	fn sender<'command>(command: &'command ServerCommand<'_>) -> Option<&'command str> {
+		match command {
+			ServerCommand::Message(message) => Some(message.character()),
+			ServerCommand::PrivateMessage(message) => Some(message.character()),
+			_ => None,
+		}
+	}
+
+	#[test]
+	fn ignore_list_filters_messages() {
+		let mut ignore_list = IgnoreList::new();
+		ignore_list.add("Markelio");
+
+		let incoming = [
+			r#"MSG {"channel":"Frontpage","character":"Markelio","message":"hello"}"#,
+			r#"MSG {"channel":"Frontpage","character":"Sarah Blitz Garisson","message":"hello"}"#,
+			r#"PRI {"character":"Markelio","message":"hello"}"#,
+			r#"PRI {"character":"Sarah Blitz Garisson","message":"hello"}"#,
+			"PIN",
+		];
+
+		let allowed: Vec<_> = incoming.into_iter()
+			.map(|wire| ServerCommand::from_wire(wire).expect("command should be parsed successfully"))
+			.filter(|command| ignore_list.allows(command))
+			.collect();
+
+		let allowed: Vec<_> = allowed.iter().map(|command| (command.command(), sender(command))).collect();
+		assert_eq!(allowed, [
+			("MSG", Some("Sarah Blitz Garisson")),
+			("PRI", Some("Sarah Blitz Garisson")),
+			("PIN", None),
+		]);
+	}
+
+	#[test]
+	fn ignore_list_serializes_as_names() {
+		let mut ignore_list = IgnoreList::new();
+		ignore_list.add("Sarah Blitz Garisson");
+		ignore_list.add("Markelio");
+
+		let json = serde_json::to_string(&ignore_list).expect("ignore list should serialize");
+		assert_eq!(json, r#"["markelio","sarah blitz garisson"]"#);
+
+		let parsed: IgnoreList = serde_json::from_str(&json).expect("ignore list should deserialize");
+		assert_eq!(parsed, ignore_list);
+
+		// names saved in any case are matched the same way
+		let parsed: IgnoreList = serde_json::from_str(r#"["Markelio"]"#).expect("ignore list should deserialize");
+		assert!(parsed.contains("markelio"));
+	}
+
+	#[test]
+	fn ignore_list_ignores_case() {
+		let mut ignore_list = IgnoreList::new();
+
+		assert!(ignore_list.add("markelio"));
+		assert!(!ignore_list.add("MARKELIO"));
+		assert!(ignore_list.contains("Markelio"));
+
+		let command = ServerCommand::from_wire(r#"PRI {"character":"Markelio","message":"hey"}"#)
+			.expect("command should be parsed successfully");
+
+		assert!(!ignore_list.allows(&command));
+
+		assert!(ignore_list.remove("MarKelio"));
+		assert!(!ignore_list.contains("markelio"));
+	}
+}
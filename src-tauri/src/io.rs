@@ -0,0 +1,2 @@
+pub mod keepalive;
+pub mod protocol;
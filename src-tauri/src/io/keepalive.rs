@@ -0,0 +1,89 @@
+//! Keeping the connection to the chat server alive.
+//!
+//! The server disconnects clients that do not answer its `PIN` in time. The
+//! decision of when to send a `PIN` is kept free of any socket or timer, so
+//! that it can be driven by whatever owns the connection.
+
+use crate::io::protocol::{ClientCommand, ServerCommand};
+use std::time::Duration;
+
+/// The interval the chat server expects a `PIN` in.
+pub const PING_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How the client keeps its connection alive.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum KeepaliveMode {
+	/// Answer every `PIN` sent by the server.
+	#[default]
+	Respond,
+
+	/// Send a `PIN` whenever the given interval has passed since the last
+	/// one, regardless of what the server sends.
+	Interval(Duration),
+}
+
+impl KeepaliveMode {
+	/// Returns the `PIN` to send in reply to `command`, if any.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::io::keepalive::KeepaliveMode;
+	/// use snowcat::io::protocol::{ClientCommand, ServerCommand};
+	///
+	/// let reply = KeepaliveMode::Respond.reply_to(&ServerCommand::Ping);
+	/// assert!(matches!(reply, Some(ClientCommand::Ping)));
+	/// ```
+	pub fn reply_to(&self, command: &ServerCommand<'_>) -> Option<ClientCommand> {
+		match (self, command) {
+			(Self::Respond, ServerCommand::Ping) => Some(ClientCommand::Ping),
+			_ => None,
+		}
+	}
+
+	/// Returns how long to wait before the next `PIN` is due, given the time
+	/// since the last one was sent. A zero duration means it is due now.
+	///
+	/// Returns `None` if pings are only sent in reply to the server.
+	pub fn next_ping_in(&self, since_last_ping: Duration) -> Option<Duration> {
+		match self {
+			Self::Respond => None,
+			Self::Interval(interval) => Some(interval.saturating_sub(since_last_ping)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io::protocol::{ClientCommand, ServerCommand};
+	use std::time::Duration;
+	use super::{KeepaliveMode, PING_INTERVAL};
+
+	#[test]
+	fn respond_only_replies_to_ping() {
+		let mode = KeepaliveMode::Respond;
+
+		assert!(matches!(mode.reply_to(&ServerCommand::Ping), Some(ClientCommand::Ping)));
+
+		let identified = ServerCommand::from_wire(r#"IDN {"character":"Markelio"}"#)
+			.expect("command should be parsed successfully");
+
+		assert!(mode.reply_to(&identified).is_none());
+
+		assert_eq!(mode.next_ping_in(Duration::from_secs(3600)), None);
+	}
+
+	#[test]
+	fn interval_counts_down() {
+		let mode = KeepaliveMode::Interval(PING_INTERVAL);
+
+		assert_eq!(mode.next_ping_in(Duration::ZERO), Some(PING_INTERVAL));
+		assert_eq!(mode.next_ping_in(Duration::from_secs(12)), Some(Duration::from_secs(18)));
+		assert_eq!(mode.next_ping_in(Duration::from_secs(30)), Some(Duration::ZERO));
+
+		// a late timer is due straight away instead of underflowing
+		assert_eq!(mode.next_ping_in(Duration::from_secs(45)), Some(Duration::ZERO));
+
+		// the timer drives the pings, so the server's are not answered
+		assert!(mode.reply_to(&ServerCommand::Ping).is_none());
+	}
+}
@@ -0,0 +1,369 @@
+//! Typed commands of the F-Chat wire protocol.
+//!
+//! Every command is sent as a three-letter identifier, optionally followed by
+//! a space and a JSON object holding its payload, such as
+//! `JCH {"channel":"Frontpage"}`. The payloads themselves are the command
+//! types of `api::remote::commands`, which are re-exported here.
+
+use crate::api::remote::commands::client::ClientHeartbeatResponse;
+use crate::api::remote::commands::server::ClientHeartbeatRequest;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub use crate::api::remote::commands::ClientVersion;
+pub use crate::api::remote::commands::client::{
+	ChannelJoin,
+	ChannelLeave,
+	ChannelSendMessage,
+	CharacterSendMessage,
+	UserChangeStatus,
+	UserIdentify,
+};
+pub use crate::api::remote::commands::server::{
+	CharacterChangeStatus,
+	CharacterJoinedChannel,
+	CharacterLeftChannel,
+	CharacterLoggedIn,
+	CharacterLoggedOut,
+	ReceiveMessage,
+	ReceivePrivateMessage,
+	ServerCharactersList,
+	UserIdentificationSuccessful,
+};
+
+pub type ProtocolResult<T> = Result<T, ProtocolError>;
+
+/// A command sent from the client to the chat server.
+#[derive(Debug)]
+pub enum ClientCommand {
+	/// `IDN`: identify with the server right after connecting.
+	Identify(UserIdentify),
+
+	/// `JCH`: join a channel.
+	JoinChannel(ChannelJoin),
+
+	/// `LCH`: leave a channel.
+	LeaveChannel(ChannelLeave),
+
+	/// `MSG`: send a message to a channel.
+	Message(ChannelSendMessage),
+
+	/// `PIN`: keep the connection alive.
+	Ping,
+
+	/// `PRI`: send a private message to a character.
+	PrivateMessage(CharacterSendMessage),
+
+	/// `STA`: change the status of the identified character.
+	Status(UserChangeStatus),
+}
+
+impl ClientCommand {
+	/// Returns the three-letter identifier of this command.
+	pub fn command(&self) -> &'static str {
+		match self {
+			Self::Identify(_) => UserIdentify::COMMAND,
+			Self::JoinChannel(_) => ChannelJoin::COMMAND,
+			Self::LeaveChannel(_) => ChannelLeave::COMMAND,
+			Self::Message(_) => ChannelSendMessage::COMMAND,
+			Self::Ping => ClientHeartbeatResponse::COMMAND,
+			Self::PrivateMessage(_) => CharacterSendMessage::COMMAND,
+			Self::Status(_) => UserChangeStatus::COMMAND,
+		}
+	}
+
+	/// Serializes this command to the wire format.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::io::protocol::{ChannelJoin, ClientCommand};
+	///
+	/// let command = ClientCommand::JoinChannel(ChannelJoin::new("Frontpage"));
+	/// assert_eq!(command.to_wire(), r#"JCH {"channel":"Frontpage"}"#);
+	///
+	/// assert_eq!(ClientCommand::Ping.to_wire(), "PIN");
+	/// ```
+	pub fn to_wire(&self) -> String {
+		let command = self.command();
+
+		match self {
+			Self::Identify(payload) => to_wire_with(command, payload),
+			Self::JoinChannel(payload) => to_wire_with(command, payload),
+			Self::LeaveChannel(payload) => to_wire_with(command, payload),
+			Self::Message(payload) => to_wire_with(command, payload),
+			Self::Ping => command.to_owned(),
+			Self::PrivateMessage(payload) => to_wire_with(command, payload),
+			Self::Status(payload) => to_wire_with(command, payload),
+		}
+	}
+}
+
+/// A command sent from the chat server to the client, borrowing its payload
+/// from the wire string it was parsed from.
+#[derive(Debug)]
+pub enum ServerCommand<'data> {
+	/// `FLN`: a character went offline.
+	CharacterOffline(CharacterLoggedOut<'data>),
+
+	/// `NLN`: a character came online.
+	CharacterOnline(CharacterLoggedIn<'data>),
+
+	/// `JCH`: a character joined a channel, which may be the identified
+	/// character.
+	ChannelJoined(CharacterJoinedChannel<'data>),
+
+	/// `LCH`: a character left a channel.
+	ChannelLeft(CharacterLeftChannel<'data>),
+
+	/// `IDN`: identification succeeded.
+	Identified(UserIdentificationSuccessful<'data>),
+
+	/// `MSG`: a message was sent to a channel.
+	Message(ReceiveMessage<'data>),
+
+	/// `LIS`: a batch of the characters that are online.
+	OnlineCharacters(ServerCharactersList<'data>),
+
+	/// `PIN`: the server checking that the client is still there.
+	Ping,
+
+	/// `PRI`: a private message was sent to the identified character.
+	PrivateMessage(ReceivePrivateMessage<'data>),
+
+	/// `STA`: a character changed their status.
+	StatusChanged(CharacterChangeStatus<'data>),
+}
+
+impl<'data> ServerCommand<'data> {
+	/// Returns the three-letter identifier of this command.
+	pub fn command(&self) -> &'static str {
+		match self {
+			Self::CharacterOffline(_) => CharacterLoggedOut::COMMAND,
+			Self::CharacterOnline(_) => CharacterLoggedIn::COMMAND,
+			Self::ChannelJoined(_) => CharacterJoinedChannel::COMMAND,
+			Self::ChannelLeft(_) => CharacterLeftChannel::COMMAND,
+			Self::Identified(_) => UserIdentificationSuccessful::COMMAND,
+			Self::Message(_) => ReceiveMessage::COMMAND,
+			Self::OnlineCharacters(_) => ServerCharactersList::COMMAND,
+			Self::Ping => ClientHeartbeatRequest::COMMAND,
+			Self::PrivateMessage(_) => ReceivePrivateMessage::COMMAND,
+			Self::StatusChanged(_) => CharacterChangeStatus::COMMAND,
+		}
+	}
+
+	/// Parses a command from the wire format.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::io::protocol::{ProtocolError, ServerCommand};
+	///
+	/// let command = ServerCommand::from_wire(r#"PRI {"character":"Markelio","message":"hey"}"#)
+	/// 	.expect("command should be parsed successfully");
+	///
+	/// assert!(matches!(command, ServerCommand::PrivateMessage(message) if message.character() == "Markelio"));
+	///
+	/// let error = ServerCommand::from_wire("XYZ").unwrap_err();
+	/// assert_eq!(error, ProtocolError::UnknownCommand("XYZ".to_owned()));
+	/// ```
+	pub fn from_wire(wire: &'data str) -> ProtocolResult<Self> {
+		let (command, payload) = split_wire(wire)?;
+
+		Ok(match command {
+			CharacterLoggedOut::COMMAND => Self::CharacterOffline(parse_payload(command, payload)?),
+			CharacterLoggedIn::COMMAND => Self::CharacterOnline(parse_payload(command, payload)?),
+			CharacterJoinedChannel::COMMAND => Self::ChannelJoined(parse_payload(command, payload)?),
+			CharacterLeftChannel::COMMAND => Self::ChannelLeft(parse_payload(command, payload)?),
+			UserIdentificationSuccessful::COMMAND => Self::Identified(parse_payload(command, payload)?),
+			ReceiveMessage::COMMAND => Self::Message(parse_payload(command, payload)?),
+			ServerCharactersList::COMMAND => Self::OnlineCharacters(parse_payload(command, payload)?),
+			ClientHeartbeatRequest::COMMAND => parse_empty(command, payload).map(|_| Self::Ping)?,
+			ReceivePrivateMessage::COMMAND => Self::PrivateMessage(parse_payload(command, payload)?),
+			CharacterChangeStatus::COMMAND => Self::StatusChanged(parse_payload(command, payload)?),
+			command => return Err(ProtocolError::UnknownCommand(command.to_owned())),
+		})
+	}
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub enum ProtocolError {
+	#[error("The command {0:?} does not start with a three-letter identifier.")]
+	MalformedCommand(String),
+
+	#[error("The command {0} is not supported.")]
+	UnknownCommand(String),
+
+	#[error("The command {0} requires a payload.")]
+	MissingPayload(String),
+
+	#[error("The command {0} does not take a payload.")]
+	UnexpectedPayload(String),
+
+	#[error("The payload of command {command} is invalid: {reason}")]
+	InvalidPayload { command: String, reason: String },
+}
+
+fn to_wire_with<T>(command: &str, payload: &T) -> String
+where T: Serialize,
+{
+	let payload = serde_json::to_string(payload).expect("command payloads should always serialize");
+	format!("{command} {payload}")
+}
+
+/// Splits a command into its identifier and its payload, if it has one.
+fn split_wire(wire: &str) -> ProtocolResult<(&str, Option<&str>)> {
+	let (command, payload) = match wire.split_once(' ') {
+		Some((command, payload)) => (command, Some(payload)),
+		None => (wire, None),
+	};
+
+	if command.len() != 3 || !command.bytes().all(|byte| byte.is_ascii_uppercase()) {
+		return Err(ProtocolError::MalformedCommand(wire.to_owned()));
+	}
+
+	Ok((command, payload))
+}
+
+fn parse_payload<'data, T>(command: &str, payload: Option<&'data str>) -> ProtocolResult<T>
+where T: Deserialize<'data>,
+{
+	let payload = payload.ok_or_else(|| ProtocolError::MissingPayload(command.to_owned()))?;
+
+	serde_json::from_str(payload).map_err(|error| ProtocolError::InvalidPayload {
+		command: command.to_owned(),
+		reason: error.to_string(),
+	})
+}
+
+fn parse_empty(command: &str, payload: Option<&str>) -> ProtocolResult<()> {
+	match payload {
+		None => Ok(()),
+		Some(_) => Err(ProtocolError::UnexpectedPayload(command.to_owned())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::api::characters::{CharacterStatus, CharacterStatusKind, StatusError};
+	use super::{
+		CharacterSendMessage,
+		ChannelLeave,
+		ClientCommand,
+		ClientVersion,
+		ProtocolError,
+		ServerCommand,
+		UserChangeStatus,
+		UserIdentify,
+	};
+
+	#[test]
+	fn parse_server_commands() {
+		let wire = r#"JCH {"channel":"ADH-c7fc4c15c858dd76d860","character":{"identity":"Markelio"},"title":"Velvet Lounge"}"#;
+		assert!(matches!(ServerCommand::from_wire(wire), Ok(ServerCommand::ChannelJoined(_))));
+
+		let wire = r#"LIS {"characters":[["Sarah Blitz Garisson","Herm","dnd","just woke up..."],["Markelio","Male","online",""]]}"#;
+		assert!(matches!(ServerCommand::from_wire(wire), Ok(ServerCommand::OnlineCharacters(_))));
+
+		let wire = r#"MSG {"channel":"Frontpage","character":"Markelio","message":"hello"}"#;
+		let command = ServerCommand::from_wire(wire).expect("command should be parsed successfully");
+
+		match command {
+			ServerCommand::Message(message) => {
+				assert_eq!(message.channel_id(), "Frontpage");
+				assert_eq!(message.character(), "Markelio");
+				assert_eq!(message.message(), "hello");
+			},
+
+			command => panic!("expected a channel message, got {command:?}"),
+		}
+
+		assert!(matches!(ServerCommand::from_wire("PIN"), Ok(ServerCommand::Ping)));
+	}
+
+	#[test]
+	fn parse_escaped_messages() {
+		let wire = r#"MSG {"channel":"Frontpage","character":"Markelio","message":"say \"hi\"\nand\/or \u00e9"}"#;
+		let command = ServerCommand::from_wire(wire).expect("command should be parsed successfully");
+
+		match command {
+			ServerCommand::Message(message) => {
+				assert_eq!(message.character(), "Markelio");
+				assert_eq!(message.message(), "say \"hi\"\nand/or \u{e9}");
+			},
+
+			command => panic!("expected a channel message, got {command:?}"),
+		}
+
+		let wire = r#"PRI {"character":"Sarah Blitz Garisson","message":"\"quoted\"\ttabbed"}"#;
+		let command = ServerCommand::from_wire(wire).expect("command should be parsed successfully");
+
+		match command {
+			ServerCommand::PrivateMessage(message) => {
+				assert_eq!(message.character(), "Sarah Blitz Garisson");
+				assert_eq!(message.message(), "\"quoted\"\ttabbed");
+			},
+
+			command => panic!("expected a private message, got {command:?}"),
+		}
+	}
+
+	#[test]
+	fn client_wire_format() {
+		let identify = ClientCommand::Identify(UserIdentify::with_ticket(
+			"account",
+			"ticket",
+			"Markelio",
+			"Snowcat",
+			ClientVersion::new(0, 1, 0),
+		));
+
+		assert_eq!(
+			identify.to_wire(),
+			r#"IDN {"account":"account","character":"Markelio","cname":"Snowcat","cversion":"0.1.0","method":"ticket","ticket":"ticket"}"#,
+		);
+
+		let leave = ClientCommand::LeaveChannel(ChannelLeave::new("Frontpage"));
+		assert_eq!(leave.to_wire(), r#"LCH {"channel":"Frontpage"}"#);
+
+		let private_message = ClientCommand::PrivateMessage(CharacterSendMessage::new("Markelio", "hey"));
+		assert_eq!(private_message.to_wire(), r#"PRI {"recipient":"Markelio","message":"hey"}"#);
+	}
+
+	#[test]
+	fn status_wire_format() {
+		let current = CharacterStatus::new(CharacterStatusKind::Online);
+
+		let status = UserChangeStatus::new(&current, CharacterStatusKind::Busy, None)
+			.expect("status change should be valid");
+
+		assert_eq!(ClientCommand::Status(status).to_wire(), r#"STA {"status":"busy","statusmsg":""}"#);
+
+		let status = UserChangeStatus::new(&current, CharacterStatusKind::Looking, Some(" open for RP "))
+			.expect("status change should be valid");
+
+		assert_eq!(ClientCommand::Status(status).to_wire(), r#"STA {"status":"looking","statusmsg":"open for RP"}"#);
+
+		let status = UserChangeStatus::new(&current, CharacterStatusKind::Offline, None);
+		assert_eq!(status.unwrap_err(), StatusError::KindNotSelectable(CharacterStatusKind::Offline));
+	}
+
+	#[test]
+	fn reject_malformed_commands() {
+		let error = |wire| ServerCommand::from_wire(wire).unwrap_err();
+
+		assert_eq!(error(""), ProtocolError::MalformedCommand(String::new()));
+		assert_eq!(error("pin"), ProtocolError::MalformedCommand("pin".to_owned()));
+		assert_eq!(
+			error(r#"MESSAGE {"message":"hi"}"#),
+			ProtocolError::MalformedCommand(r#"MESSAGE {"message":"hi"}"#.to_owned()),
+		);
+
+		assert_eq!(error("XYZ {}"), ProtocolError::UnknownCommand("XYZ".to_owned()));
+		assert_eq!(error("FLN"), ProtocolError::MissingPayload("FLN".to_owned()));
+		assert_eq!(error("PIN {}"), ProtocolError::UnexpectedPayload("PIN".to_owned()));
+
+		assert!(matches!(
+			error(r#"MSG {"channel":"Frontpage","message":"hi"}"#),
+			ProtocolError::InvalidPayload { command, .. } if command == "MSG",
+		));
+	}
+}
@@ -2,6 +2,9 @@
 
 pub mod api;
 pub mod client;
+pub mod io;
+pub mod session;
 pub mod socket;
 pub mod state;
 pub mod util;
+pub mod window;
@@ -0,0 +1,164 @@
+use crate::client::ChannelList;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tauri::async_runtime::RwLock;
+
+/// The name of the file the session state is stored in, inside the app's
+/// configuration directory.
+pub const STATE_FILE_NAME: &str = "session.toml";
+
+/// The channel re-selected on launch when the saved channel no longer exists.
+pub const FALLBACK_CHANNEL: &str = "Frontpage";
+
+/// The channel or conversation that was last active in the client.
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "id", rename_all = "lowercase")]
+pub enum LastActive {
+	Channel(String),
+	Conversation(String),
+}
+
+impl LastActive {
+	/// Pick what to re-select on launch, once the channel list has been
+	/// populated.
+	///
+	/// A saved channel is only picked if it still exists; otherwise, the
+	/// `fallback` channel is picked if it exists. Conversations are always
+	/// picked, as a conversation can be reopened whether or not the other
+	/// character is online.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::api::channels::ChannelInfo;
+	/// use snowcat::client::ChannelList;
+	/// use snowcat::session::LastActive;
+	///
+	/// let mut channels = ChannelList::new();
+	/// channels.insert(ChannelInfo {
+	/// 	id: "Frontpage".to_owned(),
+	/// 	title: "Frontpage".to_owned(),
+	/// 	character_count: 1042,
+	/// 	is_official: true,
+	/// }).unwrap();
+	///
+	/// let saved = LastActive::Channel("ADH-0123456789abcdef".to_owned());
+	///
+	/// assert_eq!(
+	/// 	LastActive::restore(Some(&saved), &channels, "Frontpage"),
+	/// 	Some(LastActive::Channel("Frontpage".to_owned())),
+	/// );
+	/// ```
+	pub fn restore(saved: Option<&LastActive>, channels: &ChannelList, fallback: &str) -> Option<LastActive> {
+		match saved {
+			Some(LastActive::Channel(id)) if channels.get(id).is_some() => Some(LastActive::Channel(id.clone())),
+			Some(conversation @ LastActive::Conversation(_)) => Some(conversation.clone()),
+
+			_ => channels.get(fallback).map(|channel| LastActive::Channel(channel.id.clone())),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub struct SessionState {
+	pub last_active: Option<LastActive>,
+}
+
+/// Saves and restores the session state, next to the window state.
+#[derive(Debug, Clone)]
+pub struct SessionStore {
+	path: PathBuf,
+}
+
+impl SessionStore {
+	/// Create a store saving to [`STATE_FILE_NAME`] in `config_dir`.
+	pub fn new(config_dir: impl AsRef<Path>) -> Self {
+		SessionStore {
+			path: config_dir.as_ref().join(STATE_FILE_NAME),
+		}
+	}
+
+	/// Load the saved session state.
+	///
+	/// A missing or unreadable state file is treated as an empty session.
+	pub fn load(&self) -> SessionState {
+		let contents = match fs::read_to_string(&self.path) {
+			Ok(contents) => contents,
+			Err(_) => return SessionState::default(),
+		};
+
+		toml::from_str(&contents).unwrap_or_else(|err| {
+			log::warn!("ignoring invalid session state in {}: {err}", self.path.display());
+			SessionState::default()
+		})
+	}
+
+	pub fn save(&self, state: &SessionState) -> io::Result<()> {
+		let contents = toml::to_string(state)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+
+		fs::write(&self.path, contents)
+	}
+
+	/// Record the channel or conversation that is now active.
+	pub fn set_last_active(&self, last_active: LastActive) -> io::Result<()> {
+		let mut state = self.load();
+		state.last_active = Some(last_active);
+
+		self.save(&state)
+	}
+}
+
+#[tauri::command]
+pub fn save_last_active(last_active: LastActive, store: tauri::State<SessionStore>) -> Result<(), String> {
+	store.set_last_active(last_active).map_err(|err| err.to_string())
+}
+
+/// Pick what to re-select on launch. Must be called once the channel list has
+/// been populated.
+#[tauri::command]
+pub async fn restore_last_active(
+	store: tauri::State<'_, SessionStore>,
+	channels: tauri::State<'_, RwLock<ChannelList>>,
+) -> Result<Option<LastActive>, String> {
+	let saved = store.load().last_active;
+	let channels = channels.read().await;
+
+	Ok(LastActive::restore(saved.as_ref(), &channels, FALLBACK_CHANNEL))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LastActive;
+	use crate::client::tests::{channel_list, OFFICIAL_CHANNELS};
+
+	#[test]
+	fn restore_picks_saved_channel() {
+		let channels = channel_list(&OFFICIAL_CHANNELS);
+		let saved = LastActive::Channel("Development".to_owned());
+		assert_eq!(LastActive::restore(Some(&saved), &channels, "Frontpage"), Some(saved));
+	}
+
+	#[test]
+	fn restore_falls_back_when_channel_is_gone() {
+		let channels = channel_list(&OFFICIAL_CHANNELS);
+		let saved = LastActive::Channel("Gone".to_owned());
+		let fallback = Some(LastActive::Channel("Frontpage".to_owned()));
+
+		assert_eq!(LastActive::restore(Some(&saved), &channels, "Frontpage"), fallback);
+		assert_eq!(LastActive::restore(None, &channels, "Frontpage"), fallback);
+		assert_eq!(LastActive::restore(Some(&saved), &channels, "Gone Too"), None);
+	}
+
+	#[test]
+	fn restore_picks_saved_conversation() {
+		let channels = channel_list(&OFFICIAL_CHANNELS);
+		let saved = LastActive::Conversation("Snow Cat".to_owned());
+		assert_eq!(LastActive::restore(Some(&saved), &channels, "Frontpage"), Some(saved));
+	}
+}
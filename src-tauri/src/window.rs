@@ -0,0 +1,346 @@
+use serde::{Deserialize, Serialize};
+use std::cmp;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+use tauri::{PhysicalPosition, PhysicalSize, Position, Size, Window, WindowEvent};
+
+/// The name of the file the window state is stored in, inside the app's
+/// configuration directory.
+pub const STATE_FILE_NAME: &str = "window.toml";
+
+/// How long the window has to stay still before its geometry is saved.
+const SAVE_DELAY: Duration = Duration::from_millis(500);
+
+/// The position, size and maximized state of a window, in physical pixels.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+pub struct WindowGeometry {
+	pub x: i32,
+	pub y: i32,
+	pub width: u32,
+	pub height: u32,
+	pub is_maximized: bool,
+}
+
+impl WindowGeometry {
+	/// Read the geometry of a window.
+	pub fn of(window: &Window) -> tauri::Result<Self> {
+		let PhysicalPosition { x, y } = window.outer_position()?;
+		let PhysicalSize { width, height } = window.outer_size()?;
+
+		Ok(WindowGeometry {
+			x,
+			y,
+			width,
+			height,
+			is_maximized: window.is_maximized()?,
+		})
+	}
+
+	/// Apply this geometry to a window.
+	pub fn apply_to(&self, window: &Window) -> tauri::Result<()> {
+		window.set_size(Size::Physical(PhysicalSize::new(self.width, self.height)))?;
+		window.set_position(Position::Physical(PhysicalPosition::new(self.x, self.y)))?;
+
+		if self.is_maximized {
+			window.maximize()?;
+		}
+
+		Ok(())
+	}
+
+	/// Move and shrink the window so that it is entirely visible on one of
+	/// `monitors`.
+	///
+	/// A window which already fits entirely on a monitor is left untouched.
+	/// Otherwise, it is moved onto the monitor it overlaps the most, or the
+	/// first monitor in the list if it overlaps none of them, which is useful
+	/// when the monitor it was last shown on has since been disconnected.
+	///
+	/// # Example
+	/// ```
+	/// use snowcat::window::{MonitorArea, WindowGeometry};
+	///
+	/// let monitors = [MonitorArea { x: 0, y: 0, width: 1920, height: 1080 }];
+	///
+	/// let geometry = WindowGeometry { x: 2200, y: 100, width: 800, height: 600, is_maximized: false };
+	/// let clamped = geometry.clamp_to_visible(&monitors);
+	///
+	/// assert_eq!((clamped.x, clamped.y), (1120, 100));
+	/// assert_eq!((clamped.width, clamped.height), (800, 600));
+	/// ```
+	pub fn clamp_to_visible(self, monitors: &[MonitorArea]) -> Self {
+		if monitors.iter().any(|monitor| monitor.contains(&self)) {
+			return self;
+		}
+
+		let monitor = monitors.iter()
+			.enumerate()
+			.max_by_key(|&(index, monitor)| (monitor.overlap(&self), cmp::Reverse(index)))
+			.map(|(_, monitor)| monitor);
+
+		let monitor = match monitor {
+			Some(monitor) => monitor,
+			None => return self,
+		};
+
+		let width = self.width.min(monitor.width);
+		let height = self.height.min(monitor.height);
+
+		let max_x = monitor.x + (monitor.width - width) as i32;
+		let max_y = monitor.y + (monitor.height - height) as i32;
+
+		WindowGeometry {
+			x: self.x.clamp(monitor.x, max_x),
+			y: self.y.clamp(monitor.y, max_y),
+			width,
+			height,
+			is_maximized: self.is_maximized,
+		}
+	}
+}
+
+/// The area covered by a monitor, in physical pixels.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct MonitorArea {
+	pub x: i32,
+	pub y: i32,
+	pub width: u32,
+	pub height: u32,
+}
+
+impl MonitorArea {
+	/// List the monitors a window can be shown on, with the primary monitor
+	/// first.
+	pub fn available(window: &Window) -> tauri::Result<Vec<Self>> {
+		let primary = window.primary_monitor()?.map(|monitor| MonitorArea::from(&monitor));
+		let mut monitors: Vec<_> = window.available_monitors()?.iter()
+			.map(MonitorArea::from)
+			.filter(|monitor| Some(monitor) != primary.as_ref())
+			.collect();
+
+		if let Some(primary) = primary {
+			monitors.insert(0, primary);
+		}
+
+		Ok(monitors)
+	}
+
+	fn contains(&self, geometry: &WindowGeometry) -> bool {
+		let (left, top, right, bottom) = self.edges();
+
+		geometry.x >= left
+			&& geometry.y >= top
+			&& geometry.x as i64 + geometry.width as i64 <= right
+			&& geometry.y as i64 + geometry.height as i64 <= bottom
+	}
+
+	fn overlap(&self, geometry: &WindowGeometry) -> u64 {
+		let (left, top, right, bottom) = self.edges();
+
+		let width = (geometry.x as i64 + geometry.width as i64).min(right) - (geometry.x as i64).max(left as i64);
+		let height = (geometry.y as i64 + geometry.height as i64).min(bottom) - (geometry.y as i64).max(top as i64);
+
+		if width > 0 && height > 0 {
+			(width * height) as u64
+		} else {
+			0
+		}
+	}
+
+	fn edges(&self) -> (i32, i32, i64, i64) {
+		(
+			self.x,
+			self.y,
+			self.x as i64 + self.width as i64,
+			self.y as i64 + self.height as i64,
+		)
+	}
+}
+
+impl From<&tauri::Monitor> for MonitorArea {
+	fn from(monitor: &tauri::Monitor) -> Self {
+		let PhysicalPosition { x, y } = *monitor.position();
+		let PhysicalSize { width, height } = *monitor.size();
+
+		MonitorArea { x, y, width, height }
+	}
+}
+
+/// Saves and restores the geometry of a window.
+#[derive(Debug, Clone)]
+pub struct WindowStateStore {
+	path: PathBuf,
+}
+
+impl WindowStateStore {
+	/// Create a store saving to [`STATE_FILE_NAME`] in `config_dir`.
+	pub fn new(config_dir: impl AsRef<Path>) -> Self {
+		WindowStateStore {
+			path: config_dir.as_ref().join(STATE_FILE_NAME),
+		}
+	}
+
+	/// Load the saved geometry, if there is one.
+	///
+	/// A missing or unreadable state file is treated as there being no saved
+	/// geometry, so that the window falls back to its default size.
+	pub fn load(&self) -> Option<WindowGeometry> {
+		let contents = fs::read_to_string(&self.path).ok()?;
+
+		match toml::from_str(&contents) {
+			Ok(geometry) => Some(geometry),
+			Err(err) => {
+				log::warn!("ignoring invalid window state in {}: {err}", self.path.display());
+				None
+			},
+		}
+	}
+
+	pub fn save(&self, geometry: &WindowGeometry) -> io::Result<()> {
+		let contents = toml::to_string(geometry)
+			.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+		if let Some(parent) = self.path.parent() {
+			fs::create_dir_all(parent)?;
+		}
+
+		fs::write(&self.path, contents)
+	}
+
+	/// Save the current geometry of a window.
+	///
+	/// While the window is maximized, its size is that of the monitor, so
+	/// the last saved position and size are kept and only the maximized state
+	/// is updated.
+	pub fn save_window(&self, window: &Window) -> anyhow::Result<()> {
+		let mut geometry = WindowGeometry::of(window)?;
+
+		if geometry.is_maximized {
+			if let Some(saved) = self.load() {
+				geometry = WindowGeometry { is_maximized: true, ..saved };
+			}
+		}
+
+		self.save(&geometry)?;
+		Ok(())
+	}
+
+	/// Restore the saved geometry of a window, making sure that it ends up on
+	/// a visible monitor.
+	pub fn restore_window(&self, window: &Window) -> tauri::Result<()> {
+		let geometry = match self.load() {
+			Some(geometry) => geometry,
+			None => return Ok(()),
+		};
+
+		geometry.clamp_to_visible(&MonitorArea::available(window)?).apply_to(window)
+	}
+
+	/// Save the geometry of a window whenever it is moved or resized.
+	///
+	/// Saves are debounced by half a second, so that dragging a window
+	/// around only writes to disk once it comes to rest. The debouncing is
+	/// done by a single worker thread per window, which stops once the window
+	/// is gone.
+	pub fn track(&self, window: &Window) {
+		let (sender, receiver) = mpsc::channel();
+
+		let store = self.clone();
+		let tracked = window.clone();
+
+		thread::spawn(move || {
+			// wait for the first event after the last save
+			while receiver.recv().is_ok() {
+				// then for the window to stay still
+				loop {
+					match receiver.recv_timeout(SAVE_DELAY) {
+						Ok(()) => continue,
+						Err(RecvTimeoutError::Timeout) => break,
+						Err(RecvTimeoutError::Disconnected) => return,
+					}
+				}
+
+				if let Err(err) = store.save_window(&tracked) {
+					log::error!("failed to save window state: {err}");
+				}
+			}
+		});
+
+		window.on_window_event(move |event| {
+			if matches!(event, WindowEvent::Moved(_) | WindowEvent::Resized(_)) {
+				// the worker only stops once this handler is dropped
+				let _ = sender.send(());
+			}
+		});
+	}
+}
+
+#[tauri::command]
+pub fn save_window_state(window: Window, store: tauri::State<WindowStateStore>) -> Result<(), String> {
+	store.save_window(&window).map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+pub fn restore_window_state(window: Window, store: tauri::State<WindowStateStore>) -> Result<(), String> {
+	store.restore_window(&window).map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MonitorArea, WindowGeometry};
+
+	const SMALL_MONITOR: MonitorArea = MonitorArea { x: 0, y: 0, width: 1366, height: 768 };
+
+	fn geometry(x: i32, y: i32, width: u32, height: u32) -> WindowGeometry {
+		WindowGeometry { x, y, width, height, is_maximized: false }
+	}
+
+	#[test]
+	fn visible_window_is_untouched() {
+		let saved = geometry(100, 50, 800, 600);
+		assert_eq!(saved.clamp_to_visible(&[SMALL_MONITOR]), saved);
+	}
+
+	#[test]
+	fn window_from_larger_monitor_is_shrunk_and_moved() {
+		// saved on a 2560x1440 monitor, restored on a 1366x768 one
+		let saved = geometry(400, 200, 1600, 1000);
+		assert_eq!(saved.clamp_to_visible(&[SMALL_MONITOR]), geometry(0, 0, 1366, 768));
+
+		let saved = geometry(900, 300, 800, 600);
+		assert_eq!(saved.clamp_to_visible(&[SMALL_MONITOR]), geometry(566, 168, 800, 600));
+	}
+
+	#[test]
+	fn window_on_disconnected_monitor_moves_to_primary() {
+		let secondary = MonitorArea { x: 1366, y: 0, width: 1920, height: 1080 };
+
+		// saved on a monitor to the left of the primary one, which is gone
+		let saved = geometry(-1500, 100, 800, 600);
+		assert_eq!(saved.clamp_to_visible(&[SMALL_MONITOR, secondary]), geometry(0, 100, 800, 600));
+	}
+
+	#[test]
+	fn window_straddling_monitors_moves_to_largest_overlap() {
+		let secondary = MonitorArea { x: 1366, y: 0, width: 1920, height: 1080 };
+
+		let saved = geometry(1200, 100, 800, 600);
+		assert_eq!(saved.clamp_to_visible(&[SMALL_MONITOR, secondary]), geometry(1366, 100, 800, 600));
+	}
+
+	#[test]
+	fn maximized_state_is_kept() {
+		let saved = WindowGeometry { is_maximized: true, ..geometry(5000, 5000, 800, 600) };
+		assert!(saved.clamp_to_visible(&[SMALL_MONITOR]).is_maximized);
+	}
+
+	#[test]
+	fn no_monitors_leaves_window_untouched() {
+		let saved = geometry(5000, 5000, 800, 600);
+		assert_eq!(saved.clamp_to_visible(&[]), saved);
+	}
+}
@@ -1,3 +1,6 @@
+pub mod logging;
+pub mod search;
+
 use wasm_bindgen::prelude::*;
 use std::sync::Arc;
 use once_cell::sync::Lazy;
@@ -45,6 +48,9 @@ pub fn main_js() -> Result<(), JsValue> {
 	#[cfg(debug_assertions)]
 	console_error_panic_hook::set_once();
 
+	logging::init(logging::LogConfig::default())
+		.map_err(|err| JsValue::from_str(&err.to_string()))?;
+
 	let app = App::new();
 	dominator::append_dom(&dominator::body(), App::render(app));
 
@@ -0,0 +1,199 @@
+use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+use once_cell::sync::Lazy;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+use thiserror::Error;
+use wasm_bindgen::JsValue;
+use web_sys::console;
+
+static CONFIG: Lazy<RwLock<LogConfig>> = Lazy::new(|| RwLock::new(LogConfig::default()));
+static LOGGER: ConsoleLogger = ConsoleLogger;
+
+/// Per-module log levels.
+///
+/// A module inherits the level of its closest configured parent, so that
+/// configuring `snowcat_signals` also covers `snowcat_signals::signal_vec`.
+/// Modules without a configured parent use the default level.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LogConfig {
+	default: LevelFilter,
+	modules: BTreeMap<String, LevelFilter>,
+}
+
+impl LogConfig {
+	pub fn new(default: LevelFilter) -> Self {
+		LogConfig {
+			default,
+			modules: BTreeMap::new(),
+		}
+	}
+
+	/// Build a configuration from a map of module paths to level names, as
+	/// stored in the settings.
+	///
+	/// Level names are parsed case-insensitively, and `off` disables logging
+	/// for a module.
+	pub fn from_map<'map, I>(default: LevelFilter, entries: I) -> Result<Self, LogConfigError>
+	where I: IntoIterator<Item = (&'map str, &'map str)>,
+	{
+		let mut config = LogConfig::new(default);
+
+		for (module, level) in entries {
+			let filter = LevelFilter::from_str(level.trim()).map_err(|_| LogConfigError::InvalidLevel {
+				module: module.to_owned(),
+				level: level.to_owned(),
+			})?;
+
+			config.set_level(module, filter);
+		}
+
+		Ok(config)
+	}
+
+	pub fn set_level(&mut self, module: &str, level: LevelFilter) {
+		self.modules.insert(module.to_owned(), level);
+	}
+
+	/// Returns the level that applies to `module`.
+	pub fn level_for(&self, module: &str) -> LevelFilter {
+		self.modules.iter()
+			.filter(|(prefix, _)| is_within(module, prefix))
+			.max_by_key(|(prefix, _)| prefix.len())
+			.map(|(_, level)| *level)
+			.unwrap_or(self.default)
+	}
+
+	/// Returns whether a record at `level` coming from `module` is logged.
+	pub fn allows(&self, module: &str, level: Level) -> bool {
+		level <= self.level_for(module)
+	}
+
+	/// The most verbose level of any module, used as the global maximum.
+	pub fn max_level(&self) -> LevelFilter {
+		self.modules.values()
+			.copied()
+			.fold(self.default, LevelFilter::max)
+	}
+}
+
+impl Default for LogConfig {
+	fn default() -> Self {
+		let mut config = LogConfig::new(LevelFilter::Warn);
+
+		config.set_level("snowcat_ui", if cfg!(debug_assertions) {
+			LevelFilter::Debug
+		} else {
+			LevelFilter::Info
+		});
+
+		config
+	}
+}
+
+#[derive(Debug, Clone, Error, Eq, PartialEq)]
+pub enum LogConfigError {
+	#[error("invalid log level {level:?} for module {module}")]
+	InvalidLevel {
+		module: String,
+		level: String,
+	},
+}
+
+/// Install the console logger with the given configuration.
+pub fn init(config: LogConfig) -> Result<(), SetLoggerError> {
+	log::set_logger(&LOGGER)?;
+	reconfigure(config);
+
+	Ok(())
+}
+
+/// Replace the configuration of the console logger, e.g. after the log levels
+/// have been changed in the settings.
+pub fn reconfigure(config: LogConfig) {
+	log::set_max_level(config.max_level());
+	*CONFIG.write().unwrap_or_else(|err| err.into_inner()) = config;
+}
+
+struct ConsoleLogger;
+
+impl Log for ConsoleLogger {
+	fn enabled(&self, metadata: &Metadata) -> bool {
+		CONFIG.read()
+			.map(|config| config.allows(metadata.target(), metadata.level()))
+			.unwrap_or(false)
+	}
+
+	fn log(&self, record: &Record) {
+		if !self.enabled(record.metadata()) {
+			return;
+		}
+
+		let message = JsValue::from_str(&format!("[{}] {}", record.target(), record.args()));
+
+		match record.level() {
+			Level::Error => console::error_1(&message),
+			Level::Warn => console::warn_1(&message),
+			Level::Info => console::info_1(&message),
+			Level::Debug => console::log_1(&message),
+			Level::Trace => console::debug_1(&message),
+		}
+	}
+
+	fn flush(&self) {}
+}
+
+/// Returns whether `module` is `prefix` or one of its submodules.
+fn is_within(module: &str, prefix: &str) -> bool {
+	match module.strip_prefix(prefix) {
+		Some(rest) => rest.is_empty() || rest.starts_with("::"),
+		None => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{LogConfig, LogConfigError};
+	use log::{Level, LevelFilter};
+
+	#[test]
+	fn config_parses_levels() {
+		let config = LogConfig::from_map(LevelFilter::Warn, [
+			("snowcat_ui", "debug"),
+			("snowcat_signals::signal_vec::merge", "TRACE"),
+			("snowcat_ui::event_bridge", " off "),
+		]).unwrap();
+
+		assert_eq!(config.level_for("snowcat_ui"), LevelFilter::Debug);
+		assert_eq!(config.level_for("snowcat_signals::signal_vec::merge"), LevelFilter::Trace);
+		assert_eq!(config.level_for("snowcat_ui::event_bridge"), LevelFilter::Off);
+		assert_eq!(config.max_level(), LevelFilter::Trace);
+	}
+
+	#[test]
+	fn config_rejects_invalid_level() {
+		let result = LogConfig::from_map(LevelFilter::Warn, [("snowcat_ui", "loud")]);
+
+		assert_eq!(result, Err(LogConfigError::InvalidLevel {
+			module: "snowcat_ui".to_owned(),
+			level: "loud".to_owned(),
+		}));
+	}
+
+	#[test]
+	fn config_filters_by_closest_module() {
+		let config = LogConfig::from_map(LevelFilter::Error, [
+			("snowcat_signals", "info"),
+			("snowcat_signals::signal_vec::merge", "trace"),
+		]).unwrap();
+
+		assert!(config.allows("snowcat_signals::signal_vec::merge", Level::Trace));
+		assert!(config.allows("snowcat_signals::signal_vec::merge::tests", Level::Trace));
+		assert!(!config.allows("snowcat_signals::signal_vec::flatten", Level::Debug));
+		assert!(config.allows("snowcat_signals::signal_vec::flatten", Level::Info));
+
+		// only whole path segments match
+		assert!(!config.allows("snowcat_signals_extra", Level::Info));
+		assert!(config.allows("snowcat_signals_extra", Level::Error));
+	}
+}
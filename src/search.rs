@@ -0,0 +1,164 @@
+use futures_signals::signal::{Mutable, Signal, SignalExt};
+use std::marker::PhantomData;
+
+/// Navigation state for an in-view search: the indices of every item that
+/// matched the last query, and a cursor pointing at the current match.
+///
+/// The state is decoupled from any specific view; a view runs a query over
+/// its items and then only has to follow [`SearchState::current_signal`].
+#[derive(Debug)]
+pub struct SearchState<T> {
+	matches: Mutable<Vec<usize>>,
+	cursor: Mutable<usize>,
+
+	item: PhantomData<fn(&T)>,
+}
+
+impl<T> SearchState<T> {
+	pub fn new() -> Self {
+		SearchState {
+			matches: Mutable::new(vec![]),
+			cursor: Mutable::new(0),
+
+			item: PhantomData,
+		}
+	}
+
+	/// Runs a query over `items`, recording the index of every item matching
+	/// `pred` and moving the cursor back to the first match.
+	pub fn run<F>(&self, items: &[T], mut pred: F)
+	where F: FnMut(&T) -> bool,
+	{
+		let matches = items.iter()
+			.enumerate()
+			.filter_map(|(index, item)| pred(item).then_some(index))
+			.collect();
+
+		self.matches.set(matches);
+		self.cursor.set(0);
+	}
+
+	/// Clears every match.
+	pub fn clear(&self) {
+		self.matches.lock_mut().clear();
+		self.cursor.set(0);
+	}
+
+	/// Returns the index of the item under the cursor, if there are any
+	/// matches.
+	pub fn current(&self) -> Option<usize> {
+		self.matches.lock_ref().get(self.cursor.get()).copied()
+	}
+
+	/// Moves the cursor to the next match, wrapping around to the first match
+	/// after the last, and returns the index of the item under it.
+	pub fn next(&self) -> Option<usize> {
+		let len = self.matches.lock_ref().len();
+
+		if len == 0 {
+			return None;
+		}
+
+		self.cursor.set((self.cursor.get() + 1) % len);
+		self.current()
+	}
+
+	/// Moves the cursor to the previous match, wrapping around to the last
+	/// match before the first, and returns the index of the item under it.
+	pub fn prev(&self) -> Option<usize> {
+		let len = self.matches.lock_ref().len();
+
+		if len == 0 {
+			return None;
+		}
+
+		self.cursor.set((self.cursor.get() + len - 1) % len);
+		self.current()
+	}
+
+	pub fn match_count(&self) -> usize {
+		self.matches.lock_ref().len()
+	}
+
+	pub fn match_count_signal(&self) -> impl Signal<Item = usize> {
+		self.matches.signal_ref(|matches| matches.len())
+	}
+
+	pub fn cursor_signal(&self) -> impl Signal<Item = usize> {
+		self.cursor.signal()
+	}
+
+	pub fn current_signal(&self) -> impl Signal<Item = Option<usize>> {
+		let matches = self.matches.clone();
+
+		self.cursor.signal()
+			.map(move |cursor| matches.lock_ref().get(cursor).copied())
+	}
+}
+
+impl<T> Default for SearchState<T> {
+	fn default() -> Self {
+		SearchState::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SearchState;
+
+	const ITEMS: [&str; 5] = ["apple", "banana", "avocado", "cherry", "apricot"];
+
+	fn starts_with_a(item: &&str) -> bool {
+		item.starts_with('a')
+	}
+
+	#[test]
+	fn search_stepping() {
+		let search = SearchState::new();
+		search.run(&ITEMS, starts_with_a);
+
+		assert_eq!(search.match_count(), 3);
+		assert_eq!(search.current(), Some(0));
+		assert_eq!(search.next(), Some(2));
+		assert_eq!(search.next(), Some(4));
+		assert_eq!(search.prev(), Some(2));
+	}
+
+	#[test]
+	fn search_wrapping() {
+		let search = SearchState::new();
+		search.run(&ITEMS, starts_with_a);
+
+		assert_eq!(search.prev(), Some(4));
+		assert_eq!(search.next(), Some(0));
+
+		search.next();
+		search.next();
+		assert_eq!(search.next(), Some(0));
+	}
+
+	#[test]
+	fn search_empty_matches() {
+		let search = SearchState::new();
+		search.run(&ITEMS, |item| item.starts_with('z'));
+
+		assert_eq!(search.match_count(), 0);
+		assert_eq!(search.current(), None);
+		assert_eq!(search.next(), None);
+		assert_eq!(search.prev(), None);
+	}
+
+	#[test]
+	fn search_rerun_resets_cursor() {
+		let search = SearchState::new();
+		search.run(&ITEMS, starts_with_a);
+
+		search.next();
+		search.next();
+		assert_eq!(search.current(), Some(4));
+
+		search.run(&ITEMS, |item| item.contains('r'));
+		assert_eq!(search.current(), Some(3));
+		assert_eq!(search.next(), Some(4));
+	}
+}